@@ -0,0 +1,219 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Error, Expr, FnArg, GenericArgument, ItemFn, LitBool, LitStr, PathArguments, ReturnType, Type};
+use syn::meta::ParseNestedMeta;
+use syn::parse::Parser;
+
+/// Struct container for parsed `#[guarded(...)]` options
+#[derive(Default)]
+struct Options {
+    roles: Vec<String>,
+    token: Option<LitStr>,
+    optional: bool,
+    claims: Option<Type>,
+    callback: Option<Expr>,
+    impersonation: Option<LitStr>,
+}
+
+/// Options implementation
+impl Options {
+    /// Parse a single option
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("roles") {
+            let roles: LitStr = meta.value()?.parse()?;
+            self.roles = roles.value()
+                .split(',')
+                .map(|role| role.trim().to_string())
+                .filter(|role| !role.is_empty())
+                .collect();
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("token") {
+            let token: LitStr = meta.value()?.parse()?;
+            match token.value().as_str() {
+                "access" | "refresh" | "web" => self.token = Some(token),
+                _ => return Err(Error::new(token.span(), "token must be one of `access`, `refresh` or `web`")),
+            }
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("optional") {
+            self.optional = match meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+                true => true,
+                false => meta.value()?.parse::<LitBool>()?.value,
+            };
+
+            return Ok(());
+        }
+
+        if meta.path.is_ident("claims") {
+            self.claims = Some(meta.value()?.parse()?);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("impersonation") {
+            self.impersonation = Some(meta.value()?.parse()?);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("callback") {
+            self.callback = Some(meta.value()?.parse()?);
+            return Ok(());
+        }
+
+        Err(meta.error("unsupported guarded option"))
+    }
+
+    /// Returns the `Guard` constructor matching the options
+    fn constructor(&self) -> syn::Result<proc_macro2::Ident> {
+        let token = self.token.as_ref().map(|token| token.value()).unwrap_or_else(|| String::from("access"));
+        match (token.as_str(), self.optional) {
+            ("access", false) => Ok(format_ident!("roles")),
+            ("access", true) => Ok(format_ident!("optional")),
+            ("refresh", false) => Ok(format_ident!("refresh")),
+            ("web", false) => Ok(format_ident!("web")),
+            _ => Err(Error::new(self.token.as_ref().map(|token| token.span()).unwrap_or_else(Span::call_site), "optional is only supported for access tokens")),
+        }
+    }
+}
+
+/// Kind of guard argument found on the handler
+enum GuardArgument {
+    Required,
+    Optional,
+}
+
+/// Check if handler argument is the type, or an `Option` of it
+fn guard_argument(ty: &Type, matches: impl Fn(&Type) -> bool) -> Option<GuardArgument> {
+    if matches(ty) {
+        return Some(GuardArgument::Required);
+    }
+
+    // Check for Option<Type>
+    if let Type::Path(path) = ty {
+        let segment = path.path.segments.last()?;
+        if segment.ident != "Option" {
+            return None;
+        }
+
+        if let PathArguments::AngleBracketed(args) = &segment.arguments {
+            if let Some(GenericArgument::Type(inner)) = args.args.first() {
+                if matches(inner) {
+                    return Some(GuardArgument::Optional);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Check if type is the claims type
+fn is_claims(ty: &Type, claims: &Type) -> bool {
+    ty.to_token_stream().to_string() == claims.to_token_stream().to_string()
+}
+
+/// Check if type is `library::paseto::Impersonation`, which the guard inserts for impersonation tokens
+fn is_impersonation(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|segment| segment.ident == "Impersonation").unwrap_or(false),
+        _ => false
+    }
+}
+
+/// Expand `#[guarded(...)]` into a handler that runs the guard before calling the original handler
+pub fn expand(args: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    // Parse options
+    let mut options = Options::default();
+    syn::meta::parser(|meta| options.parse(meta)).parse2(args)?;
+
+    let claims = options.claims.clone()
+        .ok_or_else(|| Error::new(Span::call_site(), "missing `claims = Type` option"))?;
+    let callback = options.callback.clone()
+        .ok_or_else(|| Error::new(Span::call_site(), "missing `callback = path` option"))?;
+    let constructor = options.constructor()?;
+    let roles = &options.roles;
+    let impersonation = options.impersonation.as_ref().map(|scope| quote! {
+        let mut __guarded_guard = __guarded_guard;
+        __guarded_guard.allow_impersonation(#scope);
+    });
+
+    // Parse handler
+    let handler: ItemFn = syn::parse2(item)?;
+    if handler.sig.asyncness.is_none() {
+        return Err(Error::new_spanned(handler.sig.fn_token, "guarded handlers must be async"));
+    }
+
+    if !handler.sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(&handler.sig.generics, "guarded handlers cannot be generic"));
+    }
+
+    // Split handler arguments into extractors and claims
+    let mut inputs = Vec::new();
+    let mut bindings = Vec::new();
+    let mut arguments = Vec::new();
+    for (index, input) in handler.sig.inputs.iter().enumerate() {
+        let typed = match input {
+            FnArg::Typed(typed) => typed,
+            FnArg::Receiver(receiver) => return Err(Error::new_spanned(receiver, "guarded handlers cannot take self")),
+        };
+
+        let ident = format_ident!("__guarded_argument_{}", index);
+        let ty = &typed.ty;
+        let impersonation_type = quote! { ::library::paseto::Impersonation };
+        match (guard_argument(ty, |item| is_claims(item, &claims)), guard_argument(ty, is_impersonation)) {
+            (Some(GuardArgument::Required), _) => bindings.push(quote! {
+                let #ident: #ty = match ::actix_web::HttpMessage::extensions_mut(&__guarded_request).remove::<#claims>() {
+                    Some(claims) => claims,
+                    None => return ::actix_web::Either::Left(::library::problems::convert(&__guarded_request, ::library::Payload::invalid_authentication_token())),
+                };
+            }),
+            (Some(GuardArgument::Optional), _) => bindings.push(quote! {
+                let #ident: #ty = ::actix_web::HttpMessage::extensions_mut(&__guarded_request).remove::<#claims>();
+            }),
+            (None, Some(GuardArgument::Required)) => bindings.push(quote! {
+                let #ident: #ty = match ::actix_web::HttpMessage::extensions(&__guarded_request).get::<#impersonation_type>().cloned() {
+                    Some(impersonation) => impersonation,
+                    None => return ::actix_web::Either::Left(::library::problems::convert(&__guarded_request, ::library::Payload::forbidden())),
+                };
+            }),
+            (None, Some(GuardArgument::Optional)) => bindings.push(quote! {
+                let #ident: #ty = ::actix_web::HttpMessage::extensions(&__guarded_request).get::<#impersonation_type>().cloned();
+            }),
+            (None, None) => inputs.push(quote! { #ident: #ty }),
+        }
+
+        arguments.push(ident);
+    }
+
+    // Retrieve handler parts
+    let attrs = &handler.attrs;
+    let vis = &handler.vis;
+    let name = &handler.sig.ident;
+    let original_inputs = &handler.sig.inputs;
+    let block = &handler.block;
+    let output = match &handler.sig.output {
+        ReturnType::Default => quote! { () },
+        ReturnType::Type(_, ty) => quote! { #ty },
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis async fn #name(__guarded_request: ::actix_web::HttpRequest, #(#inputs),*) -> ::actix_web::Either<::actix_web::HttpResponse, #output> {
+            async fn __guarded_handler(#original_inputs) -> #output #block
+
+            let __guarded_guard = ::library::Guard::<#claims>::#constructor(vec![#(String::from(#roles)),*], Some(#callback));
+            #impersonation
+            if let Err(response) = __guarded_guard.check(&__guarded_request) {
+                return ::actix_web::Either::Left(::library::problems::convert(&__guarded_request, response));
+            }
+
+            #(#bindings)*
+
+            ::actix_web::Either::Right(__guarded_handler(#(#arguments),*).await)
+        }
+    })
+}
@@ -0,0 +1,42 @@
+mod guarded;
+mod placeholder;
+
+use proc_macro::TokenStream;
+use syn::DeriveInput;
+
+/// Declares a `Guard` check on an actix handler
+///
+/// Options:
+/// - `claims` - Claims type returned by the callback (required)
+/// - `callback` - Guard callback (required)
+/// - `roles` - Comma separated allowed roles
+/// - `token` - Token kind, either `access` (default), `refresh` or `web`
+/// - `optional` - Allows requests without token, claims are then given as `Option<T>`
+/// - `impersonation` - Scope that allows impersonation tokens on this handler
+///
+/// A handler argument typed as the claims type (or `Option` of it) receives the claims taken
+/// from the guard, and `Option<Impersonation>` tells if an admin is acting as the user.
+/// Place `#[guarded]` below route macros like `#[get("/")]`.
+///
+/// See `library::guarded` for an example.
+#[proc_macro_attribute]
+pub fn guarded(args: TokenStream, item: TokenStream) -> TokenStream {
+    guarded::expand(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives the placeholder methods `new`, `from`, `from_string`, `to`, `is_empty` and `normalize`,
+/// plus `try_from`, `try_from_string` and `try_to` returning `Result<_, Errors>` instead of defaulting
+///
+/// The struct must implement `Default`, `PartialEq`, `Serialize`, `Deserialize` and `Sanitize`.
+/// Keep a hand written `new` or `normalize` with `#[placeholder(skip(new, normalize))]`.
+///
+/// See `library::Placeholder` for an example.
+#[proc_macro_derive(Placeholder, attributes(placeholder))]
+pub fn placeholder(item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as DeriveInput);
+    placeholder::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,117 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+/// Struct container for parsed `#[placeholder(...)]` options
+#[derive(Default)]
+struct Options {
+    skip_new: bool,
+    skip_normalize: bool,
+}
+
+/// Options implementation
+impl Options {
+    /// Parse options from the struct attributes, e.g. `#[placeholder(skip(new, normalize))]`
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("placeholder")) {
+            attr.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("skip") {
+                    return Err(meta.error("unsupported placeholder option"));
+                }
+
+                meta.parse_nested_meta(|method| {
+                    match method.path.get_ident().map(|ident| ident.to_string()).as_deref() {
+                        Some("new") => options.skip_new = true,
+                        Some("normalize") => options.skip_normalize = true,
+                        _ => return Err(method.error("only `new` and `normalize` can be skipped")),
+                    }
+
+                    Ok(())
+                })
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Expand `#[derive(Placeholder)]` into the placeholder methods
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    if !matches!(input.data, syn::Data::Struct(_)) {
+        return Err(Error::new_spanned(&input.ident, "Placeholder can only be derived for structs"));
+    }
+
+    let options = Options::parse(&input)?;
+    let name = &input.ident;
+    let label = name.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let new = (!options.skip_new).then(|| quote! {
+        #[doc = concat!("Create new ", #label, " instance")]
+        pub fn new() -> Self {
+            <Self as ::std::default::Default>::default()
+        }
+    });
+
+    let normalize = (!options.skip_normalize).then(|| quote! {
+        #[doc = concat!("Normalize ", #label, " by performing sanitation")]
+        #[allow(dead_code)]
+        pub fn normalize(&mut self) -> &mut Self {
+            ::library::placeholders::Sanitize::sanitize(self);
+            self
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #new
+
+            #[doc = concat!("Convert custom struct type to ", #label, ", falls back to default on error")]
+            #[allow(dead_code)]
+            pub fn from<T: ::serde::Serialize>(input: T) -> Self {
+                Self::try_from(input).unwrap_or_default()
+            }
+
+            #[doc = concat!("Convert custom struct type to ", #label)]
+            #[allow(dead_code)]
+            pub fn try_from<T: ::serde::Serialize>(input: T) -> ::std::result::Result<Self, ::library::Errors> {
+                ::library::placeholders::convert(&input, #label)
+            }
+
+            #[doc = concat!("Convert json string to ", #label, ", falls back to default on error")]
+            #[allow(dead_code)]
+            pub fn from_string<T: Into<String>>(input: T) -> Self {
+                Self::try_from_string(input).unwrap_or_default()
+            }
+
+            #[doc = concat!("Convert json string to ", #label)]
+            #[allow(dead_code)]
+            pub fn try_from_string<T: Into<String>>(input: T) -> ::std::result::Result<Self, ::library::Errors> {
+                ::library::placeholders::parse(&input.into(), #label)
+            }
+
+            #[doc = concat!("Convert ", #label, " to custom struct type T, falls back to default on error")]
+            #[allow(dead_code)]
+            pub fn to<T>(&self) -> T
+                where T: ::serde::de::DeserializeOwned + Default
+            {
+                self.try_to().unwrap_or_default()
+            }
+
+            #[doc = concat!("Convert ", #label, " to custom struct type T")]
+            #[allow(dead_code)]
+            pub fn try_to<T: ::serde::de::DeserializeOwned>(&self) -> ::std::result::Result<T, ::library::Errors> {
+                ::library::placeholders::convert(self, #label)
+            }
+
+            #[doc = concat!("Check if ", #label, " has no value")]
+            #[allow(dead_code)]
+            pub fn is_empty(&self) -> bool {
+                *self == <Self as ::std::default::Default>::default()
+            }
+
+            #normalize
+        }
+    })
+}
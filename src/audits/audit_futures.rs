@@ -0,0 +1,57 @@
+use actix_web::{Error, HttpMessage};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse};
+use futures::{ready, Future};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::marker::PhantomData;
+
+use crate::audits::{Event, Writer};
+
+/// AuditFuture struct
+#[pin_project]
+pub struct AuditFuture<S, B, T: 'static> where S: Service<ServiceRequest>, {
+    #[pin]
+    pub fut: S::Future,
+    pub event: Event,
+    pub writer: Writer,
+    pub subject: Option<fn(&T) -> Option<String>>,
+    pub _phantom: PhantomData<B>,
+}
+
+/// Implement Future for AuditFuture
+impl<S, B, T> Future for AuditFuture<S, B, T>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        T: 'static,
+{
+    type Output = Result<ServiceResponse<B>, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let result = ready!(this.fut.poll(cx));
+
+        // Complete event from response
+        let mut event = this.event.clone();
+        match &result {
+            Ok(res) => {
+                event.capture(res.request());
+                event.status = res.status().as_u16();
+
+                // Set subject from claims
+                if let Some(subject) = this.subject {
+                    event.subject = res.request()
+                        .extensions()
+                        .get::<T>()
+                        .and_then(subject);
+                }
+            },
+            Err(err) => event.status = err.as_response_error().status_code().as_u16()
+        }
+
+        // Queue event for the background writer
+        this.writer.send(event);
+
+        Poll::Ready(result)
+    }
+}
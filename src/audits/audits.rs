@@ -0,0 +1,131 @@
+use actix_web::Error;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use futures::future::{ok, Ready};
+
+use crate::audits::{AuditMiddleware, Event, Sink, Writer};
+use crate::DBPool;
+
+/// Audit struct middleware which emits an audit event for every request
+///
+/// Wrap it outside of `Guard` so the guard decision is available once the response comes back.
+/// Events are written to the sink by a background thread, see `Writer`.
+pub struct Audit<T: 'static> {
+    pub writer: Writer,
+    pub subject: Option<fn(&T) -> Option<String>>,
+}
+
+/// Audit implementation
+impl<T> Audit<T> {
+    /// Creates Audit instance with a custom sink
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::{Audit, Sink};
+    ///
+    /// // Create actor struct
+    /// pub struct Actor {
+    ///     id: String,
+    ///     first_name: String,
+    ///     last_name: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     // Infer actor as generic type for audit
+    ///     let audit = Audit::<Actor>::new(Sink::File(String::from("audits.log")), Some(|actor| Some(actor.id.clone())));
+    /// }
+    /// ```
+    pub fn new(sink: Sink, subject: Option<fn(&T) -> Option<String>>) -> Self {
+        Self {
+            writer: Writer::new(sink),
+            subject,
+        }
+    }
+
+    /// Creates Audit instance that appends json lines to a file
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::{App, web};
+    /// use library::audits::Audit;
+    ///
+    /// // Create actor struct
+    /// pub struct Actor {
+    ///     id: String,
+    ///     first_name: String,
+    ///     last_name: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     App::new()
+    ///         .wrap(Audit::<Actor>::file("audits.log", Some(|actor| Some(actor.id.clone()))));
+    /// }
+    /// ```
+    pub fn file<P: Into<String>>(path: P, subject: Option<fn(&T) -> Option<String>>) -> Self {
+        Self::new(Sink::File(path.into()), subject)
+    }
+
+    /// Creates Audit instance that inserts events into a postgres table
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::Audit;
+    /// use library::DBPool;
+    ///
+    /// // Create actor struct
+    /// pub struct Actor {
+    ///     id: String,
+    ///     first_name: String,
+    ///     last_name: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     let audit = Audit::<Actor>::postgres(DBPool::Others, "audits", None);
+    /// }
+    /// ```
+    pub fn postgres<N: Into<String>>(pool: DBPool, table: N, subject: Option<fn(&T) -> Option<String>>) -> Self {
+        Self::new(Sink::Postgres(pool, table.into()), subject)
+    }
+
+    /// Creates Audit instance that hands every event to a callback
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::Audit;
+    ///
+    /// // Create actor struct
+    /// pub struct Actor {
+    ///     id: String,
+    ///     first_name: String,
+    ///     last_name: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     let audit = Audit::<Actor>::callback(|event| println!("{:?}", event.to_json()), None);
+    /// }
+    /// ```
+    pub fn callback(callback: fn(&Event), subject: Option<fn(&T) -> Option<String>>) -> Self {
+        Self::new(Sink::Callback(callback), subject)
+    }
+}
+
+/// Middleware factory is `Transform` trait
+impl<S, B, T> Transform<S, ServiceRequest> for Audit<T>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        T: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = AuditMiddleware<S, T>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(AuditMiddleware {
+            service,
+            writer: self.writer.clone(),
+            subject: self.subject,
+        })
+    }
+}
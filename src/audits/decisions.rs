@@ -0,0 +1,31 @@
+use serde::{Serialize, Deserialize};
+
+/// Guard decision recorded for an audited request
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    Allowed,
+    Denied,
+    Expired,
+}
+
+/// Decision implementation
+impl Decision {
+    /// Convert decision to &str type
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::Decision;
+    ///
+    /// fn main() {
+    ///     println!("{:?}", Decision::Expired.as_str());
+    /// }
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Decision::Allowed => "allowed",
+            Decision::Denied => "denied",
+            Decision::Expired => "expired",
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use actix_web::{HttpMessage, HttpRequest};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::audits::{Decision, Outcome};
+use crate::paseto::Impersonation;
+use crate::user_agent::{self, UserAgentDevice};
+use crate::UserAgent;
+
+/// Struct container for a structured audit event
+#[derive(Debug, Clone, Serialize)]
+pub struct Event {
+    pub route: String,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<Decision>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<UserAgentDevice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Default implementation for Event
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            route: String::default(),
+            method: String::default(),
+            path: String::default(),
+            status: 0,
+            decision: None,
+            reason: None,
+            subject: None,
+            actor: None,
+            device: None,
+            ip: None,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Event implementation
+impl Event {
+    /// Create new Event instance
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::Event;
+    ///
+    /// fn main() {
+    ///     let event = Event::new();
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fill route, guard outcome and user agent details from a request
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::test::TestRequest;
+    /// use library::audits::Event;
+    ///
+    /// fn main() {
+    ///     let req = TestRequest::get().uri("/users").to_http_request();
+    ///
+    ///     let mut event = Event::new();
+    ///     event.capture(&req);
+    /// }
+    /// ```
+    pub fn capture(&mut self, req: &HttpRequest) -> &mut Self {
+        // Set route and method
+        self.method = req.method().to_string();
+        self.path = req.path().to_string();
+        self.route = req.match_pattern().unwrap_or(self.path.clone());
+
+        // Set guard outcome
+        let outcome = req.extensions().get::<Outcome>().cloned();
+        if let Some(outcome) = outcome {
+            self.decision = Some(outcome.decision);
+            self.reason = outcome.reason;
+        }
+
+        // Set admin acting as the subject
+        self.actor = req.extensions().get::<Impersonation>().map(|item| item.actor.clone());
+
+        // Set device and ip from user agent, otherwise fallback to connection info
+        let user_agent = req.extensions().get::<UserAgent>().cloned();
+        match user_agent {
+            Some(user_agent) => {
+                self.device = Some(user_agent.device);
+                self.ip = user_agent.ip;
+            },
+            None => {
+                let ip = user_agent::get_ip(req);
+                self.ip = (!ip.is_empty()).then_some(ip);
+            }
+        }
+
+        self
+    }
+
+    /// Convert self to json value
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+    }
+}
@@ -0,0 +1,43 @@
+use actix_web::Error;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse};
+use std::task::{Context, Poll};
+use std::marker::PhantomData;
+
+use crate::audits::{AuditFuture, Event, Writer};
+
+/// AuditMiddleware service struct
+pub struct AuditMiddleware<S, T: 'static> {
+    pub service: S,
+    pub writer: Writer,
+    pub subject: Option<fn(&T) -> Option<String>>,
+}
+
+/// Service implementation for AuditMiddleware
+impl<S, B, T> Service<ServiceRequest> for AuditMiddleware<S, T>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        T: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = AuditFuture<S, B, T>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        // Capture request details in case the inner service fails
+        let mut event = Event::new();
+        event.capture(req.request());
+
+        AuditFuture {
+            fut: self.service.call(req),
+            event,
+            writer: self.writer.clone(),
+            subject: self.subject,
+            _phantom: PhantomData,
+        }
+    }
+}
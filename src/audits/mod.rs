@@ -0,0 +1,17 @@
+pub mod audit_futures;
+pub mod audits;
+pub mod decisions;
+pub mod events;
+pub mod middlewares;
+pub mod outcomes;
+pub mod sinks;
+pub mod writers;
+
+pub use crate::audits::audit_futures::AuditFuture;
+pub use crate::audits::audits::Audit;
+pub use crate::audits::decisions::Decision;
+pub use crate::audits::events::Event;
+pub use crate::audits::middlewares::AuditMiddleware;
+pub use crate::audits::outcomes::Outcome;
+pub use crate::audits::sinks::Sink;
+pub use crate::audits::writers::Writer;
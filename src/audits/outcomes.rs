@@ -0,0 +1,46 @@
+use actix_web::HttpMessage;
+
+use crate::audits::Decision;
+
+/// Guard outcome stored in the request extensions so the audit middleware can pick it up
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub decision: Decision,
+    pub reason: Option<String>,
+}
+
+/// Outcome implementation
+impl Outcome {
+    /// Create new outcome instance
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::{Decision, Outcome};
+    ///
+    /// fn main() {
+    ///     let outcome = Outcome::new(Decision::Denied, Some("Invalid authentication token"));
+    /// }
+    /// ```
+    pub fn new<T: Into<String>>(decision: Decision, reason: Option<T>) -> Self {
+        Self {
+            decision,
+            reason: reason.map(|item| item.into()),
+        }
+    }
+
+    /// Store outcome in the request extensions, replacing any previous outcome
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::test::TestRequest;
+    /// use library::audits::{Decision, Outcome};
+    ///
+    /// fn main() {
+    ///     let req = TestRequest::default().to_srv_request();
+    ///     Outcome::new(Decision::Allowed, None::<String>).record(&req);
+    /// }
+    /// ```
+    pub fn record<R: HttpMessage>(self, req: &R) {
+        req.extensions_mut().insert(self);
+    }
+}
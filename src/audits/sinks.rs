@@ -0,0 +1,109 @@
+use diesel::RunQueryDsl;
+use diesel::sql_types::{Integer, Jsonb, Nullable, Text, Timestamptz};
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use crate::audits::Event;
+use crate::databases;
+use crate::DBPool;
+use crate::Errors;
+
+/// Audit sink enum which decides where audit events are written to
+///
+/// The postgres sink expects a table with the following columns:
+/// ```sql
+/// CREATE TABLE audits (
+///     id BIGSERIAL PRIMARY KEY,
+///     route TEXT NOT NULL,
+///     method TEXT NOT NULL,
+///     path TEXT NOT NULL,
+///     status INTEGER NOT NULL,
+///     decision TEXT,
+///     reason TEXT,
+///     subject TEXT,
+///     actor TEXT,
+///     device JSONB,
+///     ip TEXT,
+///     created_at TIMESTAMPTZ NOT NULL
+/// );
+/// ```
+#[derive(Clone)]
+pub enum Sink {
+    File(String),
+    Postgres(DBPool, String),
+    Callback(fn(&Event)),
+}
+
+/// Sink implementation
+impl Sink {
+    /// Write audit event to sink
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::{Event, Sink};
+    ///
+    /// fn main() {
+    ///     let sink = Sink::Callback(|event| println!("{:?}", event));
+    ///     let result = sink.write(&Event::new());
+    /// }
+    /// ```
+    pub fn write(&self, event: &Event) -> Result<(), Errors> {
+        match self {
+            Sink::File(path) => {
+                // Open file in append mode
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path);
+
+                if file.is_err() {
+                    return Err(Errors::new("Unable to open audit log file"));
+                }
+
+                // Write event as json line
+                let line = serde_json::to_string(event).unwrap_or_default();
+                match writeln!(file.unwrap(), "{}", line) {
+                    Ok(_) => Ok(()),
+                    Err(_) => Err(Errors::new("Unable to write audit log file"))
+                }
+            },
+            Sink::Postgres(pool, table) => {
+                // Validate table name before interpolating it
+                let table = databases::quote_identifier(table)?;
+
+                // Retrieve database connection
+                let mut conn = pool.get()?;
+
+                // Set query
+                let query = format!(
+                    "INSERT INTO {} (route, method, path, status, decision, reason, subject, actor, device, ip, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+                    table
+                );
+
+                // Insert event
+                let result = diesel::sql_query(query)
+                    .bind::<Text, _>(event.route.clone())
+                    .bind::<Text, _>(event.method.clone())
+                    .bind::<Text, _>(event.path.clone())
+                    .bind::<Integer, _>(i32::from(event.status))
+                    .bind::<Nullable<Text>, _>(event.decision.map(|item| item.as_str().to_string()))
+                    .bind::<Nullable<Text>, _>(event.reason.clone())
+                    .bind::<Nullable<Text>, _>(event.subject.clone())
+                    .bind::<Nullable<Text>, _>(event.actor.clone())
+                    .bind::<Nullable<Jsonb>, _>(event.device.as_ref().and_then(|item| serde_json::to_value(item).ok()))
+                    .bind::<Nullable<Text>, _>(event.ip.clone())
+                    .bind::<Timestamptz, _>(event.created_at)
+                    .execute(&mut conn);
+
+                match result {
+                    Ok(_) => Ok(()),
+                    Err(error) => Err(Errors::database("Unable to write audit event to database").with_source(error))
+                }
+            },
+            Sink::Callback(callback) => {
+                (callback)(event);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+use std::thread;
+
+use crate::audits::{Event, Sink};
+use crate::Errors;
+
+/// Maximum number of events waiting to be written before new ones are dropped
+pub const CAPACITY: usize = 1024;
+
+/// Struct container for the background writer of audit events
+///
+/// Sink writes (file io, database connection and insert) run on a dedicated thread so they never block the actix workers.
+#[derive(Clone)]
+pub struct Writer {
+    sender: SyncSender<Event>,
+}
+
+/// Writer implementation
+impl Writer {
+    /// Create writer and spawn the thread draining events into the sink
+    ///
+    /// Example
+    /// ```
+    /// use library::audits::{Event, Sink, Writer};
+    ///
+    /// fn main() {
+    ///     let writer = Writer::new(Sink::Callback(|event| println!("{:?}", event)));
+    ///     writer.send(Event::new());
+    /// }
+    /// ```
+    pub fn new(sink: Sink) -> Self {
+        let (sender, receiver) = sync_channel::<Event>(CAPACITY);
+
+        let spawned = thread::Builder::new()
+            .name(String::from("audit-writer"))
+            .spawn(move || {
+                for event in receiver {
+                    if let Err(error) = sink.write(&event) {
+                        Errors::new(format!("Audit event for {} was not recorded: {}", event.path, error)).logged();
+                    }
+                }
+            });
+
+        if spawned.is_err() {
+            Errors::new("Unable to start audit writer").logged();
+        }
+
+        Self {
+            sender,
+        }
+    }
+
+    /// Queue event without waiting, events are dropped and logged when the queue is full
+    pub fn send(&self, event: Event) {
+        match self.sender.try_send(event) {
+            Ok(_) => {},
+            Err(TrySendError::Full(event)) => {
+                Errors::new(format!("Audit queue is full, event for {} was dropped", event.path)).logged();
+            },
+            Err(TrySendError::Disconnected(event)) => {
+                Errors::new(format!("Audit writer stopped, event for {} was dropped", event.path)).logged();
+            }
+        }
+    }
+}
@@ -1,14 +1,17 @@
-use actix_web::{HttpResponse, Result, web};
+use actix_web::{HttpRequest, HttpResponse, Result, web};
 use actix_web::http::{header::{CacheControl, CacheDirective}, StatusCode};
 use handlebars::Handlebars;
 use std::collections::HashMap;
 
+use crate::correlations::RequestId;
+use crate::locales;
 use crate::Payload;
 
 /// Struct container for catchers options
 pub struct Options {
     pub cache_directives: u32,
     pub mime_html: String,
+    pub template_403_path: String,
     pub template_404_path: String,
 }
 
@@ -18,12 +21,13 @@ impl Default for Options {
         Self {
             cache_directives: 86400u32,
             mime_html: String::from("text/html; charset=utf-8"),
+            template_403_path: String::from("error/403.html"),
             template_404_path: String::from("error/404.html")
         }
     }
 }
 
-/// Creates a not found page. To be used under actix's `default_service`
+/// Creates a not found page, rendered with the request `locale` for the `t` helper and the exposed `request_id`. To be used under actix's `default_service`
 ///
 /// Example
 /// ```
@@ -39,15 +43,15 @@ impl Default for Options {
 ///         );
 /// }
 /// ```
-pub async fn not_found_page(hbs: web::Data<Handlebars<'_>>) -> Result<HttpResponse> {
+pub async fn not_found_page(req: HttpRequest, hbs: web::Data<Handlebars<'_>>) -> Result<HttpResponse> {
     // Initialize options
     let options = Options::default();
 
     // Return response result
-    options.http_response_page(hbs, &options.template_404_path, StatusCode::NOT_FOUND)
+    options.http_response_page(hbs, &options.template_404_path, StatusCode::NOT_FOUND, &req)
 }
 
-/// Creates a not found json response. To be used under actix's `default_service`
+/// Creates a not found json response, or Problem Details when preferred by the request. To be used under actix's `default_service`
 ///
 /// Example
 /// ```
@@ -64,10 +68,7 @@ pub async fn not_found_page(hbs: web::Data<Handlebars<'_>>) -> Result<HttpRespon
 /// }
 /// ```
 pub async fn not_found_json() -> Payload {
-    let mut payload = Payload::new(404);
-    payload.error = String::from("Page Not Found");
-
-    payload
+    Payload::page_not_found()
 }
 
 /// Creates a not found page. For non async middleware
@@ -91,6 +92,23 @@ pub fn not_found_middleware(hbs: web::Data<Handlebars<'_>>) -> HttpResponse {
         .body(serde_json::to_string(&body).unwrap())
 }
 
+/// Creates a forbidden page. For non async middleware
+pub fn forbidden_middleware(hbs: web::Data<Handlebars<'_>>) -> HttpResponse {
+    // Initialize options
+    let options = Options::default();
+
+    // Set empty hashmap context
+    let context:HashMap<String, String> = HashMap::new();
+
+    // Set body
+    let body = hbs.render(&options.template_403_path, &context).unwrap_or_default();
+
+    // Return http response
+    HttpResponse::Forbidden()
+        .content_type(options.mime_html.clone())
+        .body(body)
+}
+
 /// Create catcher implementations
 impl Options {
     /// Creates new instance
@@ -112,16 +130,21 @@ impl Options {
         Self {
             cache_directives,
             mime_html: mime_html_bindings,
+            template_403_path: String::from("error/403.html"),
             template_404_path: template_404_path_bindings,
         }
     }
 
     /// Returns a `Result<HttpResponse>` type and displays a page in actix web
-    fn http_response_page<T>(&self, hbs: web::Data<Handlebars<'_>>, template: T, status_code: StatusCode) -> Result<HttpResponse>
+    fn http_response_page<T>(&self, hbs: web::Data<Handlebars<'_>>, template: T, status_code: StatusCode, req: &HttpRequest) -> Result<HttpResponse>
         where T: Into<String>
     {
-        // Set empty hashmap context
-        let context:HashMap<String, String> = HashMap::new();
+        // Set locale and exposed request id context
+        let mut context:HashMap<String, String> = HashMap::new();
+        context.insert(String::from("locale"), locales::negotiate(req));
+        if let Some(request_id) = RequestId::find(req).filter(|item| item.is_exposed) {
+            context.insert(String::from("request_id"), request_id.id);
+        }
 
         // Set body
         let body = hbs.render(&template.into(), &context).unwrap();
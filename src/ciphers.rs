@@ -55,13 +55,13 @@ impl Cipher {
         // Retrieve master key
         let result = std::env::var("MASTER_KEY");
         if result.is_err() {
-            return Err(Errors::new("Master key is missing"));
+            return Err(Errors::config("Master key is missing"));
         }
 
         // Decode master key
         let result = base64_url::decode(&result.unwrap());
         if result.is_err() {
-            return Err(Errors::new("Invalid master key"))
+            return Err(Errors::config("Invalid master key"))
         }
 
         // Set master key
@@ -70,13 +70,13 @@ impl Cipher {
         // Retrieve web key
         let result = std::env::var("WEB_KEY");
         if result.is_err() {
-            return Err(Errors::new("Web key is missing"));
+            return Err(Errors::config("Web key is missing"));
         }
 
         // Decode master key
         let result = base64_url::decode(&result.unwrap());
         if result.is_err() {
-            return Err(Errors::new("Invalid web key"))
+            return Err(Errors::config("Invalid web key"))
         }
 
         // Set web key
@@ -0,0 +1,43 @@
+use actix_web::Error;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use futures::{ready, Future};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::marker::PhantomData;
+
+use crate::correlations::{self, RequestId};
+
+/// CorrelationFuture struct, polls the inner service within the request id scope
+#[pin_project]
+pub struct CorrelationFuture<S, B> where S: Service<ServiceRequest>, {
+    #[pin]
+    pub fut: S::Future,
+    pub header: String,
+    pub request_id: RequestId,
+    pub _phantom: PhantomData<B>,
+}
+
+/// Implement Future for CorrelationFuture
+impl<S, B> Future for CorrelationFuture<S, B>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+{
+    type Output = Result<ServiceResponse<B>, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let fut = this.fut;
+        let mut result = ready!(correlations::scope(&this.request_id.id, || fut.poll(cx)));
+
+        // Echo request id
+        if let Ok(res) = result.as_mut() {
+            if let (Ok(name), Ok(value)) = (HeaderName::try_from(this.header.as_str()), HeaderValue::from_str(&this.request_id.id)) {
+                res.headers_mut().insert(name, value);
+            }
+        }
+
+        Poll::Ready(result)
+    }
+}
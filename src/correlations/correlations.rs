@@ -0,0 +1,87 @@
+use actix_web::Error;
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use futures::future::{ok, Ready};
+
+use crate::correlations::CorrelationMiddleware;
+
+/// Correlation struct middleware which accepts or generates a request id, stores it in request
+/// extensions and echoes it in the response headers
+///
+/// Wrap it outermost so guards, catchers and audits see the request id.
+///
+/// Example
+/// ```
+/// use actix_web::App;
+/// use library::correlations::Correlation;
+///
+/// fn main() {
+///     let mut correlation = Correlation::new();
+///     correlation.set_exposed(true);
+///
+///     App::new().wrap(correlation);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Correlation {
+    pub header: String,
+    pub is_trusted: bool,
+    pub is_exposed: bool,
+}
+
+/// Default implementation for Correlation
+impl Default for Correlation {
+    fn default() -> Self {
+        Self {
+            header: String::from("X-Request-Id"),
+            is_trusted: false,
+            is_exposed: false,
+        }
+    }
+}
+
+/// Correlation implementation
+impl Correlation {
+    /// Creates Correlation instance using the `X-Request-Id` header
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set header name
+    pub fn set_header<T: Into<String>>(&mut self, header: T) -> &mut Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Set if incoming request ids are reused, off by default so clients cannot choose the ids of their logs.
+    /// Enable it behind a proxy or gateway that sets the header
+    pub fn set_trusted(&mut self, is_trusted: bool) -> &mut Self {
+        self.is_trusted = is_trusted;
+        self
+    }
+
+    /// Set if payloads and catcher pages include the request id
+    pub fn set_exposed(&mut self, is_exposed: bool) -> &mut Self {
+        self.is_exposed = is_exposed;
+        self
+    }
+}
+
+/// Middleware factory is `Transform` trait
+impl<S, B> Transform<S, ServiceRequest> for Correlation
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = CorrelationMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(CorrelationMiddleware {
+            service,
+            correlation: self.clone(),
+        })
+    }
+}
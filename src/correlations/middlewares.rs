@@ -0,0 +1,50 @@
+use actix_web::{Error, HttpMessage};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse};
+use std::task::{Context, Poll};
+use std::marker::PhantomData;
+
+use crate::correlations::{self, Correlation, CorrelationFuture, RequestId};
+
+/// CorrelationMiddleware service struct
+pub struct CorrelationMiddleware<S> {
+    pub service: S,
+    pub correlation: Correlation,
+}
+
+/// Service implementation for CorrelationMiddleware
+impl<S, B> Service<ServiceRequest> for CorrelationMiddleware<S>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = CorrelationFuture<S, B>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        // Accept incoming request id or generate a new one
+        let incoming = req.headers()
+            .get(self.correlation.header.as_str())
+            .and_then(|item| item.to_str().ok())
+            .filter(|item| self.correlation.is_trusted && RequestId::is_valid(item))
+            .map(RequestId::new);
+
+        let mut request_id = incoming.unwrap_or_else(RequestId::generate);
+        request_id.is_exposed = self.correlation.is_exposed;
+        req.extensions_mut().insert(request_id.clone());
+
+        // Call inner service within the request id scope
+        let fut = correlations::scope(&request_id.id, || self.service.call(req));
+
+        CorrelationFuture {
+            fut,
+            header: self.correlation.header.clone(),
+            request_id,
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+pub mod correlation_futures;
+pub mod correlations;
+pub mod middlewares;
+pub mod request_ids;
+
+use log::Level;
+use std::cell::RefCell;
+use std::fmt::Display;
+
+pub use crate::correlations::correlation_futures::CorrelationFuture;
+pub use crate::correlations::correlations::Correlation;
+pub use crate::correlations::middlewares::CorrelationMiddleware;
+pub use crate::correlations::request_ids::RequestId;
+
+thread_local! {
+    /// Request id of the request currently being polled on this thread
+    static CURRENT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run callback with the request id as the current one, e.g. while polling a request or a scheduled task
+///
+/// Example
+/// ```
+/// use library::correlations;
+///
+/// fn main() {
+///     correlations::scope("job-42", || {
+///         assert_eq!(correlations::current().unwrap(), "job-42");
+///     });
+///
+///     assert!(correlations::current().is_none());
+/// }
+/// ```
+pub fn scope<F, R>(id: &str, callback: F) -> R
+    where F: FnOnce() -> R
+{
+    let previous = CURRENT.with(|current| current.replace(Some(id.to_string())));
+    let result = callback();
+    CURRENT.with(|current| current.replace(previous));
+
+    result
+}
+
+/// Retrieve current request id
+pub fn current() -> Option<String> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Log message through the `log` crate prefixed with the current request id, e.g. `[request_id=...] Unable to upload your file`
+///
+/// Example
+/// ```
+/// use library::correlations;
+/// use log::Level;
+///
+/// fn main() {
+///     correlations::scope("job-42", || correlations::log(Level::Warn, "Unable to read logs folder"));
+/// }
+/// ```
+pub fn log<T: Display>(level: Level, message: T) {
+    match current() {
+        Some(id) => ::log::log!(level, "[request_id={}] {}", id, message),
+        None => ::log::log!(level, "{}", message)
+    }
+}
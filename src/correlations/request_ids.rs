@@ -0,0 +1,95 @@
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::error::InternalError;
+use futures::future::{err, ok, Ready};
+use rand::Rng;
+use std::fmt::Display;
+
+use crate::{Errors, Payload};
+
+/// Struct container for the request id stored in request extensions by `Correlation`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestId {
+    pub id: String,
+    pub is_exposed: bool,
+}
+
+/// Display implementation for RequestId
+impl Display for RequestId {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.write_str(&self.id)
+    }
+}
+
+/// FromRequest implementation for RequestId
+///
+/// Example
+/// ```
+/// use actix_web::{App, web};
+/// use library::correlations::{Correlation, RequestId};
+///
+/// async fn index(request_id: RequestId) -> String {
+///     format!("Handled {}", request_id)
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .wrap(Correlation::new())
+///         .route("/", web::get().to(index));
+/// }
+/// ```
+impl FromRequest for RequestId {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut DevPayload) -> Self::Future {
+        match req.extensions().get::<RequestId>() {
+            Some(request_id) => ok(request_id.clone()),
+            None => err(InternalError::from_response(
+                Errors::config("Missing correlation middleware"),
+                Payload::invalid_server_config()
+            ).into())
+        }
+    }
+}
+
+/// RequestId implementation
+impl RequestId {
+    /// Creates request id
+    pub fn new<T: Into<String>>(id: T) -> Self {
+        Self {
+            id: id.into(),
+            is_exposed: false,
+        }
+    }
+
+    /// Creates random request id formatted as a v4 uuid
+    ///
+    /// Example
+    /// ```
+    /// use library::correlations::RequestId;
+    ///
+    /// fn main() {
+    ///     let request_id = RequestId::generate();
+    ///     assert!(RequestId::is_valid(&request_id.id));
+    /// }
+    /// ```
+    pub fn generate() -> Self {
+        let mut bytes: [u8; 16] = rand::thread_rng().gen();
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        Self::new(format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32]))
+    }
+
+    /// Check if an incoming request id is safe to reuse, up to 128 letters, digits, `-`, `_`, `.` or `:`
+    pub fn is_valid(id: &str) -> bool {
+        !id.is_empty() && id.len() <= 128 && id.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+    }
+
+    /// Retrieve request id from request extensions
+    pub fn find(req: &HttpRequest) -> Option<Self> {
+        req.extensions().get::<RequestId>().cloned()
+    }
+}
@@ -4,6 +4,7 @@ use diesel::pg::PgConnection;
 use diesel::r2d2::{Pool, PooledConnection, ConnectionManager, PoolError};
 
 use super::Errors;
+use crate::tenants::{Tenant, TenantConnection};
 
 /// Create PgPool type which is basically a `Pool<ConnectionManager<PgConnection>>`
 pub type PgPool = Pool<ConnectionManager<PgConnection>>;
@@ -60,17 +61,41 @@ impl DBPool {
         return match self {
             DBPool::Postgres(_pool) => {
                 let pool = _pool.get();
-                if pool.is_err() {
-                    return Err(Errors::new("Unable to initialize your database pool"));
+                if let Err(error) = pool {
+                    return Err(Errors::database("Unable to initialize your database pool").with_source(error));
                 }
 
                 let conn:PgPooledConnection = pool.unwrap();
 
                 Ok(conn)
             },
-            DBPool::Others => Err(Errors::new("Unable to initialize your database pool"))
+            DBPool::Others => Err(Errors::database("Unable to initialize your database pool"))
         }
     }
+
+    /// Get database connection with the tenant schema set as `search_path`
+    ///
+    /// Example
+    /// ```
+    /// use library::{databases, DBPool};
+    /// use library::tenants::Tenant;
+    ///
+    /// fn main() {
+    ///     // Set database pool instance
+    ///     let result = databases::stage();
+    ///
+    ///     if result.is_ok() {
+    ///         let pool = DBPool::new(result.unwrap().clone());
+    ///         let tenant = Tenant::new("acme", "tenant_").unwrap();
+    ///
+    ///         // Get tenant scoped connection from pool
+    ///         let conn = pool.get_tenant(&tenant);
+    ///     }
+    /// }
+    /// ```
+    pub fn get_tenant(&self, tenant: &Tenant) -> Result<TenantConnection, Errors> {
+        TenantConnection::new(self.get()?, tenant)
+    }
 }
 
 /// Returns a connection from the PgPool directly
@@ -82,8 +107,8 @@ pub fn pool_conn(pool: &PgPool) -> Result<PgPooledConnection, PoolError> {
 pub fn stage() -> Result<PgPool, Errors> {
     // Set database url
     let result = env::var( "DATABASE_URL");
-    if result.is_err() {
-        return Err(Errors::new("Failed to parse DATABASE_URL. Please make sure you had a valid env value"));
+    if let Err(error) = result {
+        return Err(Errors::config("Failed to parse DATABASE_URL. Please make sure you had a valid env value").with_source(error));
     }
 
     // Set url
@@ -92,10 +117,32 @@ pub fn stage() -> Result<PgPool, Errors> {
     // Create a default R2D2 Postgres DB Pool
     let manager = ConnectionManager::<PgConnection>::new(url);
     let builder = Pool::builder().build(manager);
-    if builder.is_err() {
-        return Err(Errors::new("Unable to initialize your database pool"));
+    if let Err(error) = builder {
+        return Err(Errors::database("Unable to initialize your database pool").with_source(error));
     }
 
     // Return builder result
     Ok(builder.unwrap())
-}
\ No newline at end of file
+}
+
+/// Validate and quote a table or column name for raw sql, e.g. `public.audits` becomes `"public"."audits"`
+///
+/// Example
+/// ```
+/// use library::databases;
+///
+/// fn main() {
+///     assert_eq!(databases::quote_identifier("public.audits").unwrap(), "\"public\".\"audits\"");
+///     assert!(databases::quote_identifier("audits; DROP TABLE users").is_err());
+/// }
+/// ```
+pub fn quote_identifier(value: &str) -> Result<String, Errors> {
+    let is_valid = !value.is_empty() && value.split('.').all(|item| {
+        !item.is_empty() && item.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+
+    match is_valid {
+        true => Ok(value.split('.').map(|item| format!("\"{}\"", item)).collect::<Vec<String>>().join(".")),
+        false => Err(Errors::new(format!("Invalid sql identifier: {}", value)))
+    }
+}
@@ -1,21 +1,114 @@
+use actix_web::{HttpResponse, ResponseError};
+use actix_web::http::StatusCode;
+use log::Level;
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::correlations;
+use crate::locales;
+use crate::Payload;
+
+/// Source error kept by database, external and config errors
+pub type Source = Arc<dyn Error + Send + Sync>;
 
 /// Struct container for errors
 #[derive(Debug, Clone)]
 pub enum Errors {
-    Message(String)
+    Message(String),
+    NotFound(String),
+    Unauthorized(String),
+    Expired(String),
+    Forbidden(String),
+    Validation(String, BTreeMap<String, Vec<String>>),
+    Conflict(String),
+    Database(String, Option<Source>),
+    External(String, Option<Source>),
+    Config(String, Option<Source>),
 }
 
 /// Display implementation for Errors
 impl Display for Errors {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fmt.write_str(self.to_string().as_str())
+        fmt.write_str(self.as_str())
     }
 }
 
 /// Error implementation for Errors
-impl Error for Errors {}
+impl Error for Errors {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Errors::Database(_, source) |
+            Errors::External(_, source) |
+            Errors::Config(_, source) => source.as_ref().map(|item| item.as_ref() as &(dyn Error + 'static)),
+            _ => None
+        }
+    }
+}
+
+/// ResponseError implementation for Errors, renders the status code with a Payload body.
+/// The response has no access to the request, wrap the app with `problems::stage()` so it honors `problems::Mode`,
+/// `Accept: application/problem+json` and `Accept-Language`
+///
+/// Example
+/// ```
+/// use actix_web::{get, HttpResponse};
+/// use library::Errors;
+///
+/// #[get("/users/{id}")]
+/// async fn find() -> Result<HttpResponse, Errors> {
+///     Err(Errors::not_found("User not found"))
+/// }
+///
+/// fn main() {
+///     actix_web::App::new()
+///         .wrap(library::problems::stage())
+///         .service(find);
+/// }
+/// ```
+impl ResponseError for Errors {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Errors::Message(_) => StatusCode::BAD_REQUEST,
+            Errors::NotFound(_) => StatusCode::NOT_FOUND,
+            Errors::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Errors::Expired(_) => StatusCode::UNAUTHORIZED,
+            Errors::Forbidden(_) => StatusCode::FORBIDDEN,
+            Errors::Validation(_, _) => StatusCode::UNPROCESSABLE_ENTITY,
+            Errors::Conflict(_) => StatusCode::CONFLICT,
+            Errors::Database(_, _) => StatusCode::INTERNAL_SERVER_ERROR,
+            Errors::External(_, _) => StatusCode::BAD_GATEWAY,
+            Errors::Config(_, _) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut payload = Payload::error(self.as_str());
+        payload.code = Some(self.status_code().as_u16());
+        if let Some(fields) = self.fields() {
+            payload.errors = serde_json::to_value(fields).unwrap_or(serde_json::Value::Null);
+        }
+
+        payload.key = self.key();
+        payload.cause = Some(self.clone());
+        payload.to_response()
+    }
+}
+
+/// Convert String to Errors
+impl From<String> for Errors {
+    fn from(value: String) -> Self {
+        Self::Message(value)
+    }
+}
+
+/// Convert &str to Errors
+impl From<&str> for Errors {
+    fn from(value: &str) -> Self {
+        Self::Message(value.to_string())
+    }
+}
 
 /// Errors implementation
 impl Errors {
@@ -33,36 +126,177 @@ impl Errors {
         Self::Message(str.into())
     }
 
-    /// Convert error to &str type
+    /// Create not found error
+    pub fn not_found<T: Into<String>>(str: T) -> Self {
+        Self::NotFound(str.into())
+    }
+
+    /// Create unauthorized error, e.g. invalid token
+    pub fn unauthorized<T: Into<String>>(str: T) -> Self {
+        Self::Unauthorized(str.into())
+    }
+
+    /// Create expired token error, responds 401 like `unauthorized` but is audited as `Decision::Expired`
+    pub fn expired<T: Into<String>>(str: T) -> Self {
+        Self::Expired(str.into())
+    }
+
+    /// Create forbidden error
+    pub fn forbidden<T: Into<String>>(str: T) -> Self {
+        Self::Forbidden(str.into())
+    }
+
+    /// Create validation error with messages per field
     ///
     /// Example
     /// ```
     /// use library::Errors;
     ///
     /// fn main() {
-    ///     println!("{:?}", Errors::new("sample error").as_str());
+    ///     let error = Errors::validation("Invalid form", vec![("email", "Email is required")]);
+    ///     assert_eq!(error.fields().unwrap()["email"], vec!["Email is required"]);
     /// }
     /// ```
-    pub fn as_str(&self) -> &str {
-        return match self {
-            Errors::Message(value) => value,
+    pub fn validation<T, F, M>(str: T, fields: Vec<(F, M)>) -> Self
+        where T: Into<String>,
+              F: Into<String>,
+              M: Into<String>
+    {
+        let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (field, message) in fields {
+            map.entry(field.into()).or_default().push(message.into());
         }
+
+        Self::Validation(str.into(), map)
     }
 
-    /// Convert error to String type
+    /// Create conflict error
+    pub fn conflict<T: Into<String>>(str: T) -> Self {
+        Self::Conflict(str.into())
+    }
+
+    /// Create database error
+    pub fn database<T: Into<String>>(str: T) -> Self {
+        Self::Database(str.into(), None)
+    }
+
+    /// Create external service error
+    pub fn external<T: Into<String>>(str: T) -> Self {
+        Self::External(str.into(), None)
+    }
+
+    /// Create configuration error
+    pub fn config<T: Into<String>>(str: T) -> Self {
+        Self::Config(str.into(), None)
+    }
+
+    /// Attach source error to database, external and config errors
     ///
     /// Example
     /// ```
+    /// use std::error::Error;
     /// use library::Errors;
     ///
     /// fn main() {
-    ///     println!("{:?}", Errors::new("sample error").to_string());
+    ///     let source = std::env::var("MISSING_KEY").unwrap_err();
+    ///     let error = Errors::config("Missing key").with_source(source);
+    ///     assert!(error.source().is_some());
     /// }
     /// ```
-    pub fn to_string(&self) -> String {
-        return match self {
-            Errors::Message(value) => String::from(value),
+    pub fn with_source<E: Error + Send + Sync + 'static>(self, source: E) -> Self {
+        let source: Option<Source> = Some(Arc::new(source));
+        match self {
+            Errors::Database(value, _) => Errors::Database(value, source),
+            Errors::External(value, _) => Errors::External(value, source),
+            Errors::Config(value, _) => Errors::Config(value, source),
+            _ => self
+        }
+    }
+
+    /// Log error and its source at the error level with the current request id, then return it
+    ///
+    /// Example
+    /// ```
+    /// use library::Errors;
+    ///
+    /// fn send() -> Result<(), Errors> {
+    ///     Err(Errors::external("Unable to upload your file").logged())
+    /// }
+    ///
+    /// fn main() {
+    ///     assert!(send().is_err());
+    /// }
+    /// ```
+    pub fn logged(self) -> Self {
+        match self.source() {
+            Some(source) => correlations::log(Level::Error, format!("{} error: {} ({})", self.category(), self, source)),
+            None => correlations::log(Level::Error, format!("{} error: {}", self.category(), self))
+        }
+
+        self
+    }
+
+    /// Retrieve field errors of validation error
+    pub fn fields(&self) -> Option<&BTreeMap<String, Vec<String>>> {
+        match self {
+            Errors::Validation(_, fields) => Some(fields),
+            _ => None
+        }
+    }
+
+    /// Retrieve message key of errors built from a built-in message (see `locales::message`), so responses are translated by `Accept-Language`
+    pub fn key(&self) -> Option<String> {
+        let catalog = locales::builtin();
+        catalog.key_of(&catalog.default_locale, self.as_str()).map(String::from)
+    }
+
+    /// Retrieve error category, used as Problem Details type
+    ///
+    /// Example
+    /// ```
+    /// use library::Errors;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Errors::not_found("User not found").category(), "not-found");
+    /// }
+    /// ```
+    pub fn category(&self) -> &'static str {
+        match self {
+            Errors::Message(_) => "bad-request",
+            Errors::NotFound(_) => "not-found",
+            Errors::Unauthorized(_) => "unauthorized",
+            Errors::Expired(_) => "expired",
+            Errors::Forbidden(_) => "forbidden",
+            Errors::Validation(_, _) => "validation",
+            Errors::Conflict(_) => "conflict",
+            Errors::Database(_, _) => "database",
+            Errors::External(_, _) => "external",
+            Errors::Config(_, _) => "config",
         }
     }
-}
 
+    /// Convert error to &str type
+    ///
+    /// Example
+    /// ```
+    /// use library::Errors;
+    ///
+    /// fn main() {
+    ///     println!("{:?}", Errors::new("sample error").as_str());
+    /// }
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Errors::Message(value) |
+            Errors::NotFound(value) |
+            Errors::Unauthorized(value) |
+            Errors::Expired(value) |
+            Errors::Forbidden(value) |
+            Errors::Validation(value, _) |
+            Errors::Conflict(value) |
+            Errors::Database(value, _) |
+            Errors::External(value, _) |
+            Errors::Config(value, _) => value,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+
+use crate::Errors;
+
+/// Struct container for an IPv4 or IPv6 CIDR range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cidr {
+    pub network: IpAddr,
+    pub prefix: u8,
+}
+
+/// Parse CIDR from string. A plain ip address is treated as a single host range
+impl FromStr for Cidr {
+    type Err = Errors;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // Split address and prefix
+        let value = value.trim();
+        let (address, prefix) = match value.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (value, None)
+        };
+
+        // Parse address
+        let network = match IpAddr::from_str(address.trim()) {
+            Ok(ip) => normalize_ip(ip),
+            Err(_) => return Err(Errors::new(format!("Invalid CIDR address: {}", value)))
+        };
+
+        // Parse prefix
+        let max = match network {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        let prefix = match prefix {
+            Some(prefix) => match prefix.trim().parse::<u8>() {
+                Ok(prefix) if prefix <= max => prefix,
+                _ => return Err(Errors::new(format!("Invalid CIDR prefix: {}", value)))
+            },
+            None => max
+        };
+
+        Ok(Self { network, prefix })
+    }
+}
+
+/// Cidr implementation
+impl Cidr {
+    /// Parse CIDR from string
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::Cidr;
+    ///
+    /// fn main() {
+    ///     let office = Cidr::parse("10.0.0.0/8");
+    ///     let vpn = Cidr::parse("2001:db8::/32");
+    /// }
+    /// ```
+    pub fn parse<T: Into<String>>(value: T) -> Result<Self, Errors> {
+        Self::from_str(&value.into())
+    }
+
+    /// Check if ip address is within the range
+    ///
+    /// Example
+    /// ```
+    /// use std::net::IpAddr;
+    /// use library::firewalls::Cidr;
+    ///
+    /// fn main() {
+    ///     let cidr = Cidr::parse("192.168.1.0/24").unwrap();
+    ///     assert!(cidr.contains(&"192.168.1.20".parse::<IpAddr>().unwrap()));
+    ///     assert!(!cidr.contains(&"192.168.2.20".parse::<IpAddr>().unwrap()));
+    /// }
+    /// ```
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.network, normalize_ip(*ip)) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - u32::from(self.prefix)).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            },
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - u32::from(self.prefix)).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            },
+            _ => false
+        }
+    }
+}
+
+/// Parse client ip from connection info value which may include a port
+///
+/// Example
+/// ```
+/// use library::firewalls::cidrs::parse_ip;
+///
+/// fn main() {
+///     let ip = parse_ip("[::1]:8080");
+/// }
+/// ```
+pub fn parse_ip<T: Into<String>>(value: T) -> Option<IpAddr> {
+    let bindings = value.into();
+    let value = bindings.trim();
+
+    IpAddr::from_str(value)
+        .ok()
+        .or_else(|| SocketAddr::from_str(value).ok().map(|item| item.ip()))
+        .or_else(|| IpAddr::from_str(value.trim_start_matches('[').trim_end_matches(']')).ok())
+        .map(normalize_ip)
+}
+
+/// Convert IPv4-mapped IPv6 addresses (::ffff:a.b.c.d) into IPv4 addresses
+fn normalize_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => IpAddr::V4(v4),
+            None => IpAddr::V6(v6)
+        },
+        ip => ip
+    }
+}
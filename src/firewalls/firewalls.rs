@@ -0,0 +1,273 @@
+use actix_web::{Error, HttpRequest, HttpResponse};
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::web::Data;
+use futures::future::{ok, Ready};
+use handlebars::Handlebars;
+use parking_lot::RwLock;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use crate::audits::{Decision, Outcome};
+use crate::catchers;
+use crate::Errors;
+use crate::firewalls::{cidrs, Cidr, FirewallMiddleware, Rules};
+use crate::guards::GuardPolicy;
+use crate::Payload;
+
+/// Firewall struct middleware which checks the client ip against CIDR allow and deny lists
+///
+/// Cloned instances share the same rules, so keep a clone around to hot reload them.
+/// The client ip is the peer address, `Forwarded` and `X-Forwarded-For` are only read when the peer is a trusted proxy.
+#[derive(Clone)]
+pub struct Firewall {
+    pub rules: Arc<RwLock<Rules>>,
+    pub allow_key: Option<String>,
+    pub deny_key: Option<String>,
+    pub json_response: bool,
+    pub trusted_proxies: Vec<Cidr>,
+}
+
+/// Firewall implementation
+impl Firewall {
+    /// Creates Firewall instance from rules
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::App;
+    /// use library::firewalls::{Firewall, Rules};
+    ///
+    /// fn main() {
+    ///     let rules = Rules::from_lists(vec!["10.0.0.0/8"], Vec::<String>::new()).unwrap();
+    ///
+    ///     App::new()
+    ///         .wrap(Firewall::new(rules));
+    /// }
+    /// ```
+    pub fn new(rules: Rules) -> Self {
+        Self {
+            rules: Arc::new(RwLock::new(rules)),
+            allow_key: None,
+            deny_key: None,
+            json_response: true,
+            trusted_proxies: Vec::new(),
+        }
+    }
+
+    /// Creates Firewall instance from comma separated environment variables
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::Firewall;
+    ///
+    /// fn main() {
+    ///     std::env::set_var("ADMIN_IP_ALLOW", "10.0.0.0/8, 2001:db8::/32");
+    ///     let firewall = Firewall::from_env("ADMIN_IP_ALLOW", "ADMIN_IP_DENY");
+    /// }
+    /// ```
+    pub fn from_env<A, D>(allow_key: A, deny_key: D) -> Result<Self, Errors>
+        where A: Into<String>,
+              D: Into<String>
+    {
+        // Create bindings
+        let allow_key = allow_key.into();
+        let deny_key = deny_key.into();
+
+        // Set firewall
+        let mut firewall = Self::new(Rules::from_env(&allow_key, &deny_key)?);
+        firewall.allow_key = Some(allow_key);
+        firewall.deny_key = Some(deny_key);
+
+        Ok(firewall)
+    }
+
+    /// Replace rules on every clone of this firewall
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::{Firewall, Rules};
+    ///
+    /// fn main() {
+    ///     let firewall = Firewall::new(Rules::new());
+    ///     firewall.set_rules(Rules::from_lists(vec!["192.168.0.0/16"], Vec::<String>::new()).unwrap());
+    /// }
+    /// ```
+    pub fn set_rules(&self, rules: Rules) {
+        *self.rules.write() = rules;
+    }
+
+    /// Reload rules from the environment variables the firewall was created with
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::Firewall;
+    ///
+    /// fn main() {
+    ///     let firewall = Firewall::from_env("ADMIN_IP_ALLOW", "ADMIN_IP_DENY").unwrap();
+    ///
+    ///     std::env::set_var("ADMIN_IP_DENY", "10.0.13.0/24");
+    ///     let result = firewall.reload();
+    /// }
+    /// ```
+    pub fn reload(&self) -> Result<(), Errors> {
+        if self.allow_key.is_none() && self.deny_key.is_none() {
+            return Err(Errors::config("Firewall was not created from environment variables"));
+        }
+
+        let allow_key = self.allow_key.clone().unwrap_or_default();
+        let deny_key = self.deny_key.clone().unwrap_or_default();
+        self.set_rules(Rules::from_env(allow_key, deny_key)?);
+
+        Ok(())
+    }
+
+    /// Set firewall to render the handlebars 403 page instead of json
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::{Firewall, Rules};
+    ///
+    /// fn main() {
+    ///     let mut firewall = Firewall::new(Rules::new());
+    ///     firewall.set_page_response();
+    /// }
+    /// ```
+    pub fn set_page_response(&mut self) -> &mut Self {
+        self.json_response = false;
+        self
+    }
+
+    /// Set CIDR ranges of the reverse proxies allowed to report the client ip through forwarded headers
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::{Firewall, Rules};
+    ///
+    /// fn main() {
+    ///     let mut firewall = Firewall::new(Rules::new());
+    ///     firewall.set_trusted_proxies(vec!["10.0.0.0/8", "::1"]).unwrap();
+    /// }
+    /// ```
+    pub fn set_trusted_proxies<T: Into<String>>(&mut self, proxies: Vec<T>) -> Result<&mut Self, Errors> {
+        self.trusted_proxies = proxies.into_iter().map(Cidr::parse).collect::<Result<Vec<Cidr>, Errors>>()?;
+        Ok(self)
+    }
+
+    /// Retrieve client ip the firewall decides on. Forwarded headers are only honored when the peer is a trusted proxy,
+    /// and the right-most forwarded address that is not a trusted proxy is used
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::test::TestRequest;
+    /// use library::firewalls::{Firewall, Rules};
+    ///
+    /// fn main() {
+    ///     let mut firewall = Firewall::new(Rules::new());
+    ///
+    ///     // Spoofed header from an untrusted peer is ignored
+    ///     let req = TestRequest::default()
+    ///         .peer_addr("203.0.113.7:5000".parse().unwrap())
+    ///         .insert_header(("X-Forwarded-For", "10.0.0.1"))
+    ///         .to_http_request();
+    ///     assert_eq!(firewall.client_ip(&req), "203.0.113.7".parse().ok());
+    ///
+    ///     // Proxy appends the real client after the spoofed value
+    ///     firewall.set_trusted_proxies(vec!["10.0.0.0/8"]).unwrap();
+    ///     let req = TestRequest::default()
+    ///         .peer_addr("10.1.2.3:5000".parse().unwrap())
+    ///         .insert_header(("X-Forwarded-For", "10.0.0.1, 198.51.100.4, 10.0.0.2"))
+    ///         .to_http_request();
+    ///     assert_eq!(firewall.client_ip(&req), "198.51.100.4".parse().ok());
+    /// }
+    /// ```
+    pub fn client_ip(&self, req: &HttpRequest) -> Option<IpAddr> {
+        let peer = cidrs::parse_ip(req.peer_addr()?.ip().to_string())?;
+        if !self.is_trusted(&peer) {
+            return Some(peer);
+        }
+
+        // Walk forwarded addresses from the closest hop, stopping at the first untrusted one
+        let mut ip = peer;
+        for item in forwarded(req).iter().rev() {
+            ip = cidrs::parse_ip(item)?;
+            if !self.is_trusted(&ip) {
+                break;
+            }
+        }
+
+        Some(ip)
+    }
+
+    // Check if ip is a trusted proxy
+    fn is_trusted(&self, ip: &IpAddr) -> bool {
+        self.trusted_proxies.iter().any(|cidr| cidr.contains(ip))
+    }
+}
+
+// Retrieve forwarded client addresses, from the original client to the closest proxy
+fn forwarded(req: &HttpRequest) -> Vec<String> {
+    let headers = req.headers();
+    let forwarded_for: Vec<String> = headers.get_all("x-forwarded-for")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect();
+
+    if !forwarded_for.is_empty() {
+        return forwarded_for;
+    }
+
+    headers.get_all("forwarded")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|element| element.split(';').find_map(|pair| {
+            let (key, value) = pair.trim().split_once('=')?;
+            key.trim().eq_ignore_ascii_case("for").then(|| value.trim().trim_matches('"').to_string())
+        }))
+        .collect()
+}
+
+/// GuardPolicy implementation, which makes the firewall usable as a leaf in `all_of`, `any_of` and `not`
+impl GuardPolicy for Firewall {
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse> {
+        // Retrieve client ip from the peer, or from forwarded headers set by trusted proxies
+        let ip = self.client_ip(req.request());
+
+        // Allow access
+        if self.rules.read().is_allowed(ip) {
+            return Ok(());
+        }
+
+        // Record denied access
+        Outcome::new(Decision::Denied, Some("IP address is not allowed")).record(req);
+
+        // Check response type
+        let hbs = req.app_data::<Data<Handlebars<'_>>>().cloned();
+        match (self.json_response, hbs) {
+            (false, Some(hbs)) => Err(catchers::forbidden_middleware(hbs)),
+            _ => Err(Payload::forbidden()),
+        }
+    }
+}
+
+/// Middleware factory is `Transform` trait
+impl<S, B> Transform<S, ServiceRequest> for Firewall
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        B: MessageBody,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = FirewallMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(FirewallMiddleware {
+            service,
+            firewall: self.clone(),
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use actix_web::Error;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse};
+use futures::future::{Either, ok, Ready};
+use std::task::{Context, Poll};
+use std::marker::PhantomData;
+
+use crate::firewalls::Firewall;
+use crate::guards::{AuthenticationFuture, GuardPolicy};
+use crate::problems;
+
+/// FirewallMiddleware service struct
+pub struct FirewallMiddleware<S> {
+    pub service: S,
+    pub firewall: Firewall,
+}
+
+/// Service implementation for FirewallMiddleware
+impl<S, B> Service<ServiceRequest> for FirewallMiddleware<S>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        B: MessageBody,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = Either<AuthenticationFuture<S, B>, Ready<Result<Self::Response, Self::Error>>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        match self.firewall.evaluate(&req) {
+            // Allow access
+            Ok(_) => Either::Left(AuthenticationFuture {
+                fut: self.service.call(req),
+                _phantom: PhantomData,
+            }),
+            // Disable access
+            Err(response) => {
+                let response = problems::convert(req.request(), response);
+                Either::Right(ok(req
+                    .into_response(response)
+                    .map_into_boxed_body()
+                    .map_into_right_body()))
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod cidrs;
+pub mod firewalls;
+pub mod middlewares;
+pub mod rules;
+
+pub use crate::firewalls::cidrs::Cidr;
+pub use crate::firewalls::firewalls::Firewall;
+pub use crate::firewalls::middlewares::FirewallMiddleware;
+pub use crate::firewalls::rules::Rules;
@@ -0,0 +1,112 @@
+use std::net::IpAddr;
+
+use crate::envs;
+use crate::Errors;
+use crate::firewalls::Cidr;
+
+/// Struct container for ip allow and deny lists
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Rules {
+    pub allow: Vec<Cidr>,
+    pub deny: Vec<Cidr>,
+}
+
+/// Rules implementation
+impl Rules {
+    /// Create new Rules instance
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::Rules;
+    ///
+    /// fn main() {
+    ///     let rules = Rules::new();
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create Rules from lists of CIDR strings
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::Rules;
+    ///
+    /// fn main() {
+    ///     let rules = Rules::from_lists(vec!["10.0.0.0/8", "fd00::/8"], vec!["10.0.13.0/24"]);
+    /// }
+    /// ```
+    pub fn from_lists<A, D>(allow: Vec<A>, deny: Vec<D>) -> Result<Self, Errors>
+        where A: Into<String>,
+              D: Into<String>
+    {
+        let mut rules = Self::new();
+
+        for item in allow {
+            rules.allow.push(Cidr::parse(item)?);
+        }
+
+        for item in deny {
+            rules.deny.push(Cidr::parse(item)?);
+        }
+
+        Ok(rules)
+    }
+
+    /// Create Rules from comma separated environment variables
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::Rules;
+    ///
+    /// fn main() {
+    ///     std::env::set_var("ADMIN_IP_ALLOW", "10.0.0.0/8, 192.168.0.0/16");
+    ///     let rules = Rules::from_env("ADMIN_IP_ALLOW", "ADMIN_IP_DENY");
+    /// }
+    /// ```
+    pub fn from_env<A, D>(allow_key: A, deny_key: D) -> Result<Self, Errors>
+        where A: Into<String>,
+              D: Into<String>
+    {
+        let allow = envs::get(allow_key);
+        let deny = envs::get(deny_key);
+
+        Self::from_lists(split_list(&allow), split_list(&deny))
+    }
+
+    /// Check if ip address passes the rules. Deny list always wins, and an empty allow list allows everyone else
+    ///
+    /// Example
+    /// ```
+    /// use library::firewalls::Rules;
+    ///
+    /// fn main() {
+    ///     let rules = Rules::from_lists(vec!["10.0.0.0/8"], vec!["10.0.13.0/24"]).unwrap();
+    ///     assert!(rules.is_allowed("10.0.1.5".parse().ok()));
+    ///     assert!(!rules.is_allowed("10.0.13.5".parse().ok()));
+    ///     assert!(!rules.is_allowed(None));
+    /// }
+    /// ```
+    pub fn is_allowed(&self, ip: Option<IpAddr>) -> bool {
+        match ip {
+            Some(ip) => {
+                if self.deny.iter().any(|cidr| cidr.contains(&ip)) {
+                    return false;
+                }
+
+                self.allow.is_empty() || self.allow.iter().any(|cidr| cidr.contains(&ip))
+            },
+            None => self.allow.is_empty() && self.deny.is_empty()
+        }
+    }
+}
+
+/// Split comma separated list and remove empty entries
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
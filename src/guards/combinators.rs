@@ -0,0 +1,126 @@
+use actix_web::{HttpMessage, HttpResponse};
+use actix_web::dev::ServiceRequest;
+
+use crate::audits::{Decision, Outcome};
+use crate::guards::{GuardPolicy, Policy};
+use crate::Payload;
+
+/// Policy that passes only when every policy passes
+pub struct AllOf {
+    pub policies: Vec<Policy>,
+}
+
+/// GuardPolicy implementation for AllOf
+impl GuardPolicy for AllOf {
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse> {
+        let mut outcome = None;
+        for policy in self.policies.iter() {
+            let (result, child) = buffered(policy, req);
+            outcome = child.or(outcome);
+
+            if let Err(response) = result {
+                // Record the rejecting policy only
+                outcome.unwrap_or_else(|| Outcome::new(Decision::Denied, Some("Request was rejected by a policy"))).record(req);
+
+                return Err(response);
+            }
+        }
+
+        outcome.unwrap_or_else(|| Outcome::new(Decision::Allowed, None::<String>)).record(req);
+
+        Ok(())
+    }
+}
+
+/// Policy that passes when any policy passes, recording only the outcome of the passing policy or of the first rejection
+///
+/// Example
+/// ```
+/// use actix_web::{HttpMessage, HttpResponse};
+/// use actix_web::test::TestRequest;
+/// use library::audits::{Decision, Outcome};
+/// use library::guards::{any_of, GuardPolicy, Policy};
+///
+/// fn main() {
+///     let policy = any_of(vec![
+///         Policy::custom(|req| {
+///             Outcome::new(Decision::Denied, Some("Missing api key")).record(req);
+///             Err(HttpResponse::Unauthorized().finish())
+///         }),
+///         Policy::custom(|req| {
+///             Outcome::new(Decision::Allowed, None::<String>).record(req);
+///             Ok(())
+///         }),
+///     ]);
+///
+///     let req = TestRequest::default().to_srv_request();
+///     assert!(policy.evaluate(&req).is_ok());
+///     assert_eq!(req.extensions().get::<Outcome>().unwrap().decision, Decision::Allowed);
+/// }
+/// ```
+pub struct AnyOf {
+    pub policies: Vec<Policy>,
+}
+
+/// GuardPolicy implementation for AnyOf
+impl GuardPolicy for AnyOf {
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse> {
+        let mut rejection = None;
+        for policy in self.policies.iter() {
+            match buffered(policy, req) {
+                (Ok(_), outcome) => {
+                    // Record the passing policy, earlier rejections are discarded
+                    outcome.unwrap_or_else(|| Outcome::new(Decision::Allowed, None::<String>)).record(req);
+
+                    return Ok(());
+                },
+                (Err(response), outcome) => {
+                    if rejection.is_none() {
+                        rejection = Some((response, outcome));
+                    }
+                }
+            }
+        }
+
+        // Record the first rejection, matching the returned response
+        let (response, outcome) = rejection.unwrap_or_else(|| (Payload::forbidden(), None));
+        outcome.unwrap_or_else(|| Outcome::new(Decision::Denied, Some("Request was rejected by every policy"))).record(req);
+
+        Err(response)
+    }
+}
+
+/// Policy that inverts another policy
+pub struct Not {
+    pub policy: Policy,
+}
+
+/// GuardPolicy implementation for Not
+impl GuardPolicy for Not {
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse> {
+        // Outcome of the inverted policy is discarded
+        match buffered(&self.policy, req).0 {
+            Ok(_) => {
+                // Record denied access
+                Outcome::new(Decision::Denied, Some("Request matched a denied policy")).record(req);
+
+                Err(Payload::forbidden())
+            },
+            Err(_) => {
+                // Record allowed access
+                Outcome::new(Decision::Allowed, None::<String>).record(req);
+
+                Ok(())
+            }
+        }
+    }
+}
+
+// Evaluate policy and take the outcome it recorded, so only the combinator records the final decision
+fn buffered(policy: &Policy, req: &ServiceRequest) -> (Result<(), HttpResponse>, Option<Outcome>) {
+    req.extensions_mut().remove::<Outcome>();
+    let result = policy.evaluate(req);
+    let outcome = req.extensions_mut().remove::<Outcome>();
+
+    (result, outcome)
+}
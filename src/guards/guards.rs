@@ -1,24 +1,37 @@
-use actix_web::Error;
+use actix_web::{Error, HttpMessage, HttpRequest, HttpResponse, ResponseError};
 use actix_web::body::{EitherBody, MessageBody};
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::Method;
 use actix_web::web::Data;
 use actix_utils::future::{ok, Ready};
+use chrono::Duration;
+use handlebars::Handlebars;
 use std::sync::{Arc, Mutex};
 
-use crate::guards::Options;
+use crate::audits::{Decision, Outcome};
+use crate::catchers;
+use crate::DBPool;
+use crate::Errors;
+use crate::guards::{GuardPolicy, Options};
 use crate::GuardMiddleware;
 use crate::Paseto;
+use crate::Payload;
 use crate::PgPooledConnection;
+use crate::strings;
+use crate::tenants::Tenant;
 
 /// RoleGuard struct middleware
 pub struct Guard<T: 'static> {
     pub roles: Option<Vec<String>>,
-    pub callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, String>>,
+    pub callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, Errors>>,
     pub has_database: Option<bool>,
     pub json_response: bool,
     pub is_optional: bool,
     pub is_refresh_token: bool,
     pub is_web_token: bool,
+    pub tenant: Option<fn(&T) -> Option<String>>,
+    pub mfa_max_age: Option<Duration>,
+    pub impersonation_scope: Option<String>,
 }
 
 /// Default implementation
@@ -49,6 +62,27 @@ impl<T> Default for Guard<T> {
             is_optional: false,
             is_refresh_token: false,
             is_web_token: false,
+            tenant: None,
+            mfa_max_age: None,
+            impersonation_scope: None,
+        }
+    }
+}
+
+/// Clone implementation, claims type does not need to be cloneable
+impl<T> Clone for Guard<T> {
+    fn clone(&self) -> Self {
+        Self {
+            roles: self.roles.clone(),
+            callback: self.callback,
+            has_database: self.has_database,
+            json_response: self.json_response,
+            is_optional: self.is_optional,
+            is_refresh_token: self.is_refresh_token,
+            is_web_token: self.is_web_token,
+            tenant: self.tenant,
+            mfa_max_age: self.mfa_max_age,
+            impersonation_scope: self.impersonation_scope.clone(),
         }
     }
 }
@@ -82,11 +116,14 @@ impl<T> Guard<T> {
             is_optional: false,
             is_refresh_token: false,
             is_web_token: false,
+            tenant: None,
+            mfa_max_age: None,
+            impersonation_scope: None,
         }
     }
 
     /// Creates Guard instance that checks for controller input
-    pub fn controller(callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, String>>) -> Self {
+    pub fn controller(callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, Errors>>) -> Self {
         Self {
             roles: Some(vec![String::from("Controller")]),
             callback,
@@ -95,11 +132,14 @@ impl<T> Guard<T> {
             is_optional: false,
             is_refresh_token: false,
             is_web_token: false,
+            tenant: None,
+            mfa_max_age: None,
+            impersonation_scope: None,
         }
     }
 
     /// Creates Guard instance that checks for roles input
-    pub fn roles(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, String>>) -> Self {
+    pub fn roles(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, Errors>>) -> Self {
         Self {
             roles: Some(roles),
             callback,
@@ -108,11 +148,14 @@ impl<T> Guard<T> {
             is_optional: false,
             is_refresh_token: false,
             is_web_token: false,
+            tenant: None,
+            mfa_max_age: None,
+            impersonation_scope: None,
         }
     }
 
     /// Creates Guard instance that checks for refresh token input
-    pub fn refresh(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, String>>) -> Self {
+    pub fn refresh(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, Errors>>) -> Self {
         Self {
             roles: Some(roles),
             callback,
@@ -121,11 +164,14 @@ impl<T> Guard<T> {
             is_optional: false,
             is_refresh_token: true,
             is_web_token: false,
+            tenant: None,
+            mfa_max_age: None,
+            impersonation_scope: None,
         }
     }
 
     /// Creates Guard instance that checks for web token input
-    pub fn web(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, String>>) -> Self {
+    pub fn web(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, Errors>>) -> Self {
         Self {
             roles: Some(roles),
             callback,
@@ -134,11 +180,14 @@ impl<T> Guard<T> {
             is_optional: false,
             is_refresh_token: false,
             is_web_token: true,
+            tenant: None,
+            mfa_max_age: None,
+            impersonation_scope: None,
         }
     }
 
     /// Creates Guard instance that checks for roles input but optional
-    pub fn optional(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, String>>) -> Self {
+    pub fn optional(roles:Vec<String>, callback: Option<fn(&mut PgPooledConnection, Options, Option<Data<Arc<Mutex<Paseto>>>>) -> Result<T, Errors>>) -> Self {
         Self {
             roles: Some(roles),
             callback,
@@ -147,6 +196,9 @@ impl<T> Guard<T> {
             is_optional: true,
             is_refresh_token: false,
             is_web_token: false,
+            tenant: None,
+            mfa_max_age: None,
+            impersonation_scope: None,
         }
     }
 
@@ -173,6 +225,282 @@ impl<T> Guard<T> {
         self.json_response = true;
         self
     }
+
+    /// Require the tenant returned from claims to match the tenant resolved by `tenants::Tenancy`
+    ///
+    /// Example
+    /// ```
+    /// use library::Guard;
+    ///
+    /// // Create actor struct
+    /// pub struct Actor {
+    ///     id: String,
+    ///     tenant_id: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     let mut guard = Guard::<Actor>::roles(vec![String::from("Admin")], None);
+    ///     guard.set_tenant(|actor| Some(actor.tenant_id.clone()));
+    /// }
+    /// ```
+    pub fn set_tenant(&mut self, tenant: fn(&T) -> Option<String>) -> &mut Self {
+        self.tenant = Some(tenant);
+        self
+    }
+
+    /// Require the token to carry multi-factor `amr` claims issued within the given age,
+    /// see `Paseto::generate_authenticated_tokens`. Web tokens cannot carry these claims and are rejected
+    ///
+    /// Example
+    /// ```
+    /// use chrono::Duration;
+    /// use library::Guard;
+    ///
+    /// // Create actor struct
+    /// pub struct Actor {
+    ///     id: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     // Changing password requires mfa within the last 5 minutes
+    ///     let mut guard = Guard::<Actor>::roles(vec![String::from("User")], None);
+    ///     guard.set_recent_mfa(Duration::minutes(5));
+    /// }
+    /// ```
+    pub fn set_recent_mfa(&mut self, max_age: Duration) -> &mut Self {
+        self.mfa_max_age = Some(max_age);
+        self
+    }
+
+    /// Allow impersonation tokens carrying the scope, see `Paseto::generate_impersonation_tokens`.
+    /// Impersonation tokens are rejected by guards without an allowed scope
+    ///
+    /// Example
+    /// ```
+    /// use library::Guard;
+    ///
+    /// // Create actor struct
+    /// pub struct Actor {
+    ///     id: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     // Support staff may view orders while logged in as a customer
+    ///     let mut guard = Guard::<Actor>::roles(vec![String::from("User")], None);
+    ///     guard.allow_impersonation("support");
+    /// }
+    /// ```
+    pub fn allow_impersonation<S: Into<String>>(&mut self, scope: S) -> &mut Self {
+        self.impersonation_scope = Some(scope.into());
+        self
+    }
+}
+
+/// Guard check implementation
+impl<T: 'static> Guard<T> {
+    /// Runs the guard against a request, inserting claims into request extensions when allowed.
+    /// Used by the middleware and by `#[guarded]` handlers
+    #[allow(clippy::result_large_err)]
+    pub fn check(&self, req: &HttpRequest) -> Result<(), HttpResponse> {
+        // Set flags and payload
+        let mut payload = Payload::invalid_authentication_token();
+
+        // Check if method is options, allow request
+        if Method::OPTIONS == *req.method() {
+            return Ok(());
+        }
+
+        // Check for handlebars
+        let hbs = req.app_data::<Data<Handlebars<'_>>>();
+
+        // TODO: Create further filter for database (case: no database in app data)
+        // Retrieve pg pool to validate token in database
+        let has_database = self.has_database;
+        let json_response = self.json_response;
+        let pool = req.app_data::<Data<DBPool>>().unwrap().get();
+        if has_database.is_some() && has_database.clone().unwrap() == true && pool.is_err() {
+            // Check response type
+            match json_response.clone() || (json_response.clone() == false && hbs.is_none()) {
+                true => payload = Payload::database_connection(),
+                false => payload = catchers::not_found_middleware(hbs.clone().unwrap().clone()),
+            }
+
+            // Record denied access
+            Outcome::new(Decision::Denied, Some("Unable to initialize database connection")).record(req);
+
+            // Return response
+            return Err(payload);
+        }
+
+        // Check if other options does not exist
+        if has_database.is_some() && has_database.clone().unwrap() == true &&
+            !pool.is_err() && self.callback.clone().is_none() && self.roles.clone().is_none() &&
+            self.is_refresh_token.clone() == false && self.is_web_token.clone() == false {
+
+            // Record allowed access
+            Outcome::new(Decision::Allowed, None::<String>).record(req);
+
+            // Allow access
+            return Ok(());
+        }
+
+        // Retrieve allowed roles
+        let roles = self.roles.clone();
+        let has_allowed_roles = match roles.is_some() {
+            true => roles.clone().unwrap().len() > 0,
+            false => false
+        };
+
+        // Check if roles exists and database does not exists
+        if has_allowed_roles && (has_database.is_none() || !has_database.clone().unwrap()) {
+            match json_response.clone() || ( json_response.clone() == false && hbs.is_none() ) {
+                true => payload = Payload::database_connection(),
+                false => payload = catchers::not_found_middleware(hbs.clone().unwrap().clone()),
+            }
+
+            // Record denied access
+            Outcome::new(Decision::Denied, Some("Roles require a database connection")).record(req);
+
+            // Return response
+            return Err(payload);
+        }
+
+        // Retrieve authorization
+        let authorization = req
+            .headers()
+            .get("Authorization")
+            .map(|h| h.to_str().unwrap_or(""))
+            .unwrap_or("")
+            .trim();
+
+        // Retrieve token
+        let token = strings::get_token(authorization)
+            .unwrap_or(String::new());
+
+        // Retrieve database connection pool
+        let mut conn = pool.unwrap();
+
+        // TODO: Create further filter for paseto (case: no paseto in app data)
+        // Retrieve paseto
+        let paseto = req.app_data::<Data<Arc<Mutex<Paseto>>>>().clone();
+
+        // Retrieve tenant resolved by tenancy middleware
+        let tenant = req.extensions().get::<Tenant>().map(|item| item.id.clone());
+
+        // Create Options
+        let guard_options = Options {
+            token: token.clone(),
+            roles,
+            json_response: self.json_response.clone(),
+            is_optional: self.is_optional.clone(),
+            is_refresh_token: self.is_refresh_token.clone(),
+            is_web_token: self.is_web_token.clone(),
+            tenant: tenant.clone()
+        };
+
+        // Retrieve callback
+        let callback = self.callback.clone();
+        if callback.is_some() {
+            let result = (callback.unwrap())(&mut conn, guard_options, paseto.cloned());
+            return match result {
+                Ok(claims) => {
+                    // Check if claims belong to the request tenant
+                    if let Some(tenant_of) = self.tenant {
+                        if tenant.is_none() || tenant_of(&claims) != tenant {
+                            // Record denied access
+                            Outcome::new(Decision::Denied, Some("Tenant does not match authentication token")).record(req);
+
+                            return Err(Payload::forbidden());
+                        }
+                    }
+
+                    // Check if token carries recent multi-factor authentication
+                    if let Some(max_age) = self.mfa_max_age {
+                        let authentication = match (self.is_web_token, paseto) {
+                            (false, Some(paseto)) => {
+                                let paseto = paseto.lock().unwrap();
+                                match self.is_refresh_token {
+                                    true => paseto.get_refresh_authentication(&token),
+                                    false => paseto.get_authentication(&token),
+                                }.ok()
+                            },
+                            _ => None
+                        };
+
+                        if !authentication.map(|item| item.is_recent_mfa(max_age)).unwrap_or(false) {
+                            // Record denied access
+                            Outcome::new(Decision::Denied, Some("Recent multi-factor authentication is required")).record(req);
+
+                            return Err(Payload::mfa_required());
+                        }
+                    }
+
+                    // Check if impersonation token is allowed for this route
+                    let impersonation = match (self.is_refresh_token || self.is_web_token, paseto) {
+                        (false, Some(paseto)) => paseto.lock().unwrap().get_impersonation(&token),
+                        _ => Ok(None)
+                    };
+
+                    // Reject malformed impersonation claims instead of treating them as a regular token
+                    let impersonation = match impersonation {
+                        Ok(impersonation) => impersonation,
+                        Err(error) => {
+                            // Record denied access
+                            Outcome::new(Decision::Denied, Some(error.as_str())).record(req);
+
+                            return Err(Errors::unauthorized(error.as_str()).error_response());
+                        }
+                    };
+
+                    if let Some(impersonation) = impersonation.as_ref() {
+                        if !self.impersonation_scope.as_ref().map(|scope| impersonation.has_scope(scope)).unwrap_or(false) {
+                            // Record denied access
+                            Outcome::new(Decision::Denied, Some("Impersonation is not allowed for this route")).record(req);
+
+                            return Err(Payload::forbidden());
+                        }
+                    }
+
+                    req.extensions_mut().insert(claims);
+                    if let Some(impersonation) = impersonation {
+                        req.extensions_mut().insert(impersonation);
+                    }
+
+                    // Record allowed access
+                    Outcome::new(Decision::Allowed, None::<String>).record(req);
+
+                    Ok(())
+                },
+                Err(error) => {
+                    // Record expired token or denied access
+                    let decision = match error {
+                        Errors::Expired(_) => Decision::Expired,
+                        _ => Decision::Denied,
+                    };
+                    Outcome::new(decision, Some(error.as_str())).record(req);
+
+                    // Render status and payload from error category
+                    let payload = error.error_response();
+
+                    // Disable access
+                    Err(payload)
+                }
+            }
+        }
+
+        // Record denied access
+        Outcome::new(Decision::Denied, Some("Invalid authentication token")).record(req);
+
+        // Disable access
+        return Err(payload);
+    }
+}
+
+/// GuardPolicy implementation, which makes every guard usable as a leaf in `all_of`, `any_of` and `not`
+impl<T: 'static> GuardPolicy for Guard<T> {
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse> {
+        self.check(req.request())
+    }
 }
 
 /// Middleware factory is `Transform` trait
@@ -190,23 +518,9 @@ impl<S, B, T> Transform<S, ServiceRequest> for Guard<T>
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
     fn new_transform(&self, service: S) -> Self::Future {
-        let roles = self.roles.clone();
-        let callback = self.callback.clone();
-        let has_database = self.has_database.clone();
-        let json_response = self.json_response.clone();
-        let is_optional = self.is_optional.clone();
-        let is_refresh_token = self.is_refresh_token.clone();
-        let is_web_token = self.is_web_token.clone();
-
         ok(GuardMiddleware {
             service,
-            roles,
-            callback,
-            has_database,
-            json_response,
-            is_optional,
-            is_refresh_token,
-            is_web_token,
+            guard: self.clone(),
         })
     }
 }
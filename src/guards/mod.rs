@@ -1,9 +1,15 @@
 pub mod authentication_futures;
+pub mod combinators;
 pub mod guards;
 pub mod middlewares;
 pub mod options;
+pub mod policies;
+pub mod policy_middlewares;
 
 pub use crate::guards::authentication_futures::AuthenticationFuture;
+pub use crate::guards::combinators::{AllOf, AnyOf, Not};
 pub use crate::guards::guards::Guard;
 pub use crate::guards::middlewares::GuardMiddleware;
-pub use crate::guards::options::Options;
\ No newline at end of file
+pub use crate::guards::options::Options;
+pub use crate::guards::policies::{all_of, any_of, not, GuardPolicy, Policy};
+pub use crate::guards::policy_middlewares::PolicyMiddleware;
@@ -2,8 +2,21 @@ use sanitizer::prelude::*;
 use serde::{Serialize, Deserialize};
 use std::default::Default;
 
+use crate::Placeholder;
+
 /// Struct container for option
-#[derive(Debug, Clone, PartialEq, Sanitize, Serialize, Deserialize)]
+///
+/// Example
+/// ```
+/// use library::guards::Options;
+///
+/// fn main() {
+///     let options = Options::from_string(r#"{"token": "My token", "json_response": true, "is_optional": false, "is_refresh_token": false, "is_web_token": false}"#);
+///     assert_eq!(options.token, "My token");
+///     assert!(Options::new().is_empty());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Placeholder, Sanitize, Serialize, Deserialize)]
 pub struct Options {
     #[sanitize(trim)]
     pub token: String,
@@ -13,6 +26,8 @@ pub struct Options {
     pub is_optional: bool,
     pub is_refresh_token: bool,
     pub is_web_token: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
 }
 
 /// Default implementation for Options
@@ -25,146 +40,7 @@ impl Default for Options {
             is_optional: false,
             is_refresh_token: false,
             is_web_token: false,
+            tenant: None,
         }
     }
 }
-
-/// Options implementation
-impl Options {
-    /// Create new Options instance
-    ///
-    /// Example
-    /// ```
-    /// use library::guards::Options;
-    ///
-    /// fn main() {
-    ///     let option = Options::new();
-    /// }
-    /// ```
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Convert custom struct type to Options
-    ///
-    /// Example
-    /// ```
-    /// use library::guards::Options;
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize)]
-    /// pub struct Options2 {
-    ///     #[serde(skip_serializing_if = "String::is_empty")]
-    ///     pub token: String,
-    ///     pub is_optional: bool,
-    /// }
-    ///
-    /// fn main() {
-    ///     let option = Options::from(Options2{token: String::from("My token"), is_optional: true});
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn from<T>(input: T) -> Self
-        where T: Serialize
-    {
-        let s = serde_json::to_string(&input).unwrap_or(String::default());
-        serde_json::from_str(&s).unwrap_or(Options::default())
-    }
-
-    /// Convert custom struct type to Options
-    ///
-    /// Example
-    /// ```
-    /// use library::guards::Options;
-    ///
-    /// fn main() {
-    ///     let input = r#"{"token": "ABC1234", "is_optional":true}"#;
-    ///     let option = Options::from_string(input);
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn from_string<T: Into<String>>(input: T) -> Self {
-        let bindings = input.into();
-        serde_json::from_str(&bindings).unwrap_or(Options::default())
-    }
-
-    /// Convert custom struct type from Options to T
-    ///
-    /// Example
-    /// ```
-    /// use library::guards::Options;
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// /// Struct container for Options2
-    /// #[derive(Debug, Clone, Serialize, Deserialize)]
-    /// pub struct Options2 {
-    ///     #[serde(skip_serializing_if = "String::is_empty")]
-    ///     pub token: String,
-    ///     pub is_optional: bool
-    /// }
-    ///
-    /// /// Note: this is always required. Default implementation for Options
-    /// impl Default for Options2 {
-    ///     fn default() -> Self {
-    ///         Self {
-    ///             token: String::default(),
-    ///             is_optional: true
-    ///         }
-    ///     }
-    /// }
-    ///
-    /// fn main() {
-    ///     let input = r#"{"token": "ABC1234", "is_optional":true}"#;
-    ///     let option = Options::from_string(input);
-    ///
-    ///     let option2 = option.to::<Options2>();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn to<T>(&self) -> T
-        where T: serde::de::DeserializeOwned + Default
-    {
-        let value = self.clone();
-        let s = serde_json::to_string(&value).unwrap_or(String::default());
-        let response:T = serde_json::from_str(&s).unwrap_or(T::default());
-
-        response
-    }
-
-    /// Check if options has no value
-    ///
-    /// Example
-    /// ```
-    /// // Import option
-    /// use library::guards::Options;
-    ///
-    /// fn main() {
-    ///     // Set option
-    ///     let option = Options::new();
-    ///     let is_empty = option.is_empty();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn is_empty(&self) -> bool {
-        self.clone() == Self::default()
-    }
-
-    /// Normalize option by performing sanitation and other important stuff
-    ///
-    /// Example
-    /// ```
-    /// // Import option
-    /// use library::guards::Options;
-    ///
-    /// fn main() {
-    ///     // Set option
-    ///     let mut option = Options::new();
-    ///     let form = option.normalize();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn normalize(&mut self) -> &mut Self {
-        self.sanitize();
-        self
-    }
-}
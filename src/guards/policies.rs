@@ -0,0 +1,175 @@
+use actix_web::{Error, HttpResponse};
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use futures::future::{ok, Ready};
+use std::rc::Rc;
+
+use crate::firewalls::Firewall;
+use crate::guards::{AllOf, AnyOf, Not, PolicyMiddleware};
+use crate::Guard;
+
+/// Trait for anything that can allow or reject a request before it reaches the handler
+pub trait GuardPolicy {
+    /// Returns `Ok` when the request passes, otherwise the response to reject it with
+    #[allow(clippy::result_large_err)]
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse>;
+}
+
+/// Custom function policy
+impl GuardPolicy for fn(&ServiceRequest) -> Result<(), HttpResponse> {
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse> {
+        (self)(req)
+    }
+}
+
+/// Policy struct middleware which runs a tree of guard policies as one actix `Transform`
+#[derive(Clone)]
+pub struct Policy {
+    pub inner: Rc<dyn GuardPolicy>,
+}
+
+/// Policy implementation
+impl Policy {
+    /// Creates Policy from any guard policy
+    pub fn new<P: GuardPolicy + 'static>(policy: P) -> Self {
+        Self {
+            inner: Rc::new(policy),
+        }
+    }
+
+    /// Creates Policy from a custom function, e.g. an api key check
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::HttpResponse;
+    /// use library::guards::Policy;
+    ///
+    /// fn main() {
+    ///     let api_key = Policy::custom(|req| {
+    ///         match req.headers().get("X-Api-Key").map(|item| item == "secret") {
+    ///             Some(true) => Ok(()),
+    ///             _ => Err(HttpResponse::Unauthorized().finish())
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    pub fn custom(callback: fn(&ServiceRequest) -> Result<(), HttpResponse>) -> Self {
+        Self::new(callback)
+    }
+}
+
+/// GuardPolicy implementation for Policy so policies can be nested
+impl GuardPolicy for Policy {
+    fn evaluate(&self, req: &ServiceRequest) -> Result<(), HttpResponse> {
+        self.inner.evaluate(req)
+    }
+}
+
+/// Convert guard into policy
+impl<T: 'static> From<Guard<T>> for Policy {
+    fn from(guard: Guard<T>) -> Self {
+        Self::new(guard)
+    }
+}
+
+/// Convert firewall into policy
+impl From<Firewall> for Policy {
+    fn from(firewall: Firewall) -> Self {
+        Self::new(firewall)
+    }
+}
+
+/// Creates policy that passes only when every policy passes. Policies run in order and stop at the first rejection
+///
+/// Example
+/// ```
+/// use actix_web::App;
+/// use library::firewalls::{Firewall, Rules};
+/// use library::guards::all_of;
+/// use library::Guard;
+///
+/// // Create actor struct
+/// pub struct Actor {
+///     id: String,
+/// }
+///
+/// fn main() {
+///     let office = Rules::from_lists(vec!["10.0.0.0/8"], Vec::<String>::new()).unwrap();
+///
+///     // Admin AND ip allowlisted
+///     App::new()
+///         .wrap(all_of(vec![
+///             Firewall::new(office).into(),
+///             Guard::<Actor>::roles(vec![String::from("Admin")], None).into(),
+///         ]));
+/// }
+/// ```
+pub fn all_of(policies: Vec<Policy>) -> Policy {
+    Policy::new(AllOf { policies })
+}
+
+/// Creates policy that passes when any policy passes. When every policy rejects, the first rejection is returned
+///
+/// Example
+/// ```
+/// use actix_web::{App, HttpResponse};
+/// use library::guards::{any_of, Policy};
+/// use library::Guard;
+///
+/// // Create actor struct
+/// pub struct Actor {
+///     id: String,
+/// }
+///
+/// fn main() {
+///     // Web token OR api key
+///     App::new()
+///         .wrap(any_of(vec![
+///             Guard::<Actor>::web(vec![String::from("User")], None).into(),
+///             Policy::custom(|req| match req.headers().contains_key("X-Api-Key") {
+///                 true => Ok(()),
+///                 false => Err(HttpResponse::Unauthorized().finish())
+///             }),
+///         ]));
+/// }
+/// ```
+pub fn any_of(policies: Vec<Policy>) -> Policy {
+    Policy::new(AnyOf { policies })
+}
+
+/// Creates policy that inverts another policy
+///
+/// Example
+/// ```
+/// use library::firewalls::{Firewall, Rules};
+/// use library::guards::not;
+///
+/// fn main() {
+///     let blocked = Rules::from_lists(vec!["203.0.113.0/24"], Vec::<String>::new()).unwrap();
+///     let policy = not(Firewall::new(blocked).into());
+/// }
+/// ```
+pub fn not(policy: Policy) -> Policy {
+    Policy::new(Not { policy })
+}
+
+/// Middleware factory is `Transform` trait
+impl<S, B> Transform<S, ServiceRequest> for Policy
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        B: MessageBody,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = PolicyMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(PolicyMiddleware {
+            service,
+            policy: self.clone(),
+        })
+    }
+}
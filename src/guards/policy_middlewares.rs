@@ -0,0 +1,49 @@
+use actix_web::Error;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse};
+use futures::future::{Either, ok, Ready};
+use std::task::{Context, Poll};
+use std::marker::PhantomData;
+
+use crate::guards::{AuthenticationFuture, GuardPolicy, Policy};
+use crate::problems;
+
+/// PolicyMiddleware service struct
+pub struct PolicyMiddleware<S> {
+    pub service: S,
+    pub policy: Policy,
+}
+
+/// Service implementation for PolicyMiddleware
+impl<S, B> Service<ServiceRequest> for PolicyMiddleware<S>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        B: MessageBody,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = Either<AuthenticationFuture<S, B>, Ready<Result<Self::Response, Self::Error>>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        match self.policy.evaluate(&req) {
+            // Allow access
+            Ok(_) => Either::Left(AuthenticationFuture {
+                fut: self.service.call(req),
+                _phantom: PhantomData,
+            }),
+            // Disable access
+            Err(payload) => {
+                let payload = problems::convert(req.request(), payload);
+                Either::Right(ok(req
+                    .into_response(payload)
+                    .map_into_boxed_body()
+                    .map_into_right_body()))
+            }
+        }
+    }
+}
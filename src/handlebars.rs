@@ -1,5 +1,7 @@
 use handlebars::Handlebars;
 
+use crate::locales::{self, Catalog};
+
 /// Struct container for handlebars options
 pub struct Options {
     pub asset_path: String,
@@ -41,8 +43,9 @@ impl Options {
     }
 }
 
-/// Stage handlebar instance
-pub fn stage(options: &Options) -> Handlebars<'static> {
+/// Stage handlebar instance, with the `t` helper translating messages of the app catalog, e.g. `{{t "page-not-found"}}`.
+/// Pass the same catalog registered with `app_data` so templates and error responses share messages
+pub fn stage(options: &Options, catalog: &Catalog) -> Handlebars<'static> {
     // Initialize handlebars
     let mut handlebars = Handlebars::new();
 
@@ -51,6 +54,9 @@ pub fn stage(options: &Options) -> Handlebars<'static> {
         .register_templates_directory(&options.extension, &options.asset_path)
         .expect("Invalid template directory path");
 
+    // Register translation helper
+    locales::register_helper(&mut handlebars, catalog.clone());
+
     // Return handlebars
     handlebars
 }
\ No newline at end of file
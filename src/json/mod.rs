@@ -0,0 +1,97 @@
+pub mod normalized;
+pub mod normalizers;
+
+use actix_web::error::JsonPayloadError;
+use actix_web::web::JsonConfig;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::locales;
+use crate::stages;
+
+pub use crate::json::normalized::Normalized;
+pub use crate::json::normalizers::Normalizer;
+
+/// Check if serde_json::Value is empty
+///
+/// Example
+/// ```
+/// use serde_json::json;
+///
+/// use library::json::is_empty;
+///
+/// fn main() {
+///     let object = json!({ "A": 65, "B": 66, "C": "test", "D": "" });
+///     let b = is_empty(&object);
+/// }
+/// ```
+pub fn is_empty<T: serde::Serialize>(item: &T) -> bool {
+    let result = serde_json::to_string(item);
+    if result.is_ok() {
+        let bindings = result.unwrap();
+        let map:HashMap<String, Value> = serde_json::from_str(&bindings).unwrap();
+
+        for (_, value) in map.iter() {
+            match () {
+                _ if value.is_array() && value.as_array().is_some() && value.as_array().unwrap().len() > 0 => return false,
+                _ if value.is_string() && value.as_str().is_some() && !value.as_str().unwrap().trim().is_empty() => return false,
+                _ if value.is_object() && value.as_object().is_some() => return false,
+                _ if value.is_boolean() && value.as_bool().is_some() => return false,
+                _ if value.is_i64() && value.as_i64().is_some() => return false,
+                _ if value.is_f64() && value.as_f64().is_some() => return false,
+                _ if value.is_u64() && value.as_u64().is_some() => return false,
+                _ => {}
+            }
+        }
+    }
+
+    true
+}
+
+/// Normalize field using the default `Normalizer` rules, falls back to the original item on error
+///
+/// Example
+/// ```
+/// use library::json;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Serialize, Deserialize)]
+/// pub struct Profile {
+///     pub name: Option<String>,
+///     pub tags: Option<Vec<String>>,
+/// }
+///
+/// fn main() {
+///     let profile = json::normalize(Profile { name: Some(" John ".to_string()), tags: Some(vec![" ".to_string()]) });
+///     assert_eq!(profile.tags, None);
+/// }
+/// ```
+pub fn normalize<T>(item: T) -> T
+    where T: Clone + serde::Serialize + serde::de::DeserializeOwned
+{
+    match serde_json::to_value(&item) {
+        Ok(value) => Normalizer::default().normalize_into(value).unwrap_or(item),
+        Err(_) => item
+    }
+}
+
+/// Create staging for json config, errors are localized by `Accept-Language` and rendered as Problem Details when preferred by the request
+///
+/// Actix's `Json` only reports missing, unknown and duplicate fields in `errors`, use `Normalized` or `ValidatedJson` for the path of type errors (`items[2].price`)
+pub fn stage(json_limit: usize) -> JsonConfig {
+    JsonConfig::default()
+        .limit(json_limit)
+        .error_handler(|err, req| {
+            // Match error
+            let (status, message, field) = match &err {
+                JsonPayloadError::ContentType => (400, locales::translate(req, "json-content-type", &[]), None),
+                JsonPayloadError::Overflow { .. } |
+                JsonPayloadError::OverflowKnownLength { .. } => (413, locales::translate(req, "json-overflow", &[]), None),
+                JsonPayloadError::Deserialize(error) => (400, locales::translate(req, "json-deserialize", &[("error", &error.to_string())]), stages::field(&error.to_string())),
+                JsonPayloadError::Payload(error) => (400, stages::payload_message(req, error), None),
+                _ => (400, locales::translate(req, "request-error", &[]), None),
+            };
+
+            stages::respond(req, err, status, message, field)
+        })
+}
@@ -0,0 +1,84 @@
+use actix_web::{Error, FromRequest, HttpRequest};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::web::{Data, Json};
+use futures::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::ops::{Deref, DerefMut};
+
+use crate::json::Normalizer;
+use crate::stages;
+
+/// Extractor that normalizes the json body with the `Normalizer` app data (or the default rules) before deserializing it
+///
+/// Example
+/// ```
+/// use actix_web::{App, web};
+/// use actix_web::web::Data;
+/// use library::json::{Normalized, Normalizer};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// pub struct Profile {
+///     pub name: String,
+///     pub bio: Option<String>,
+/// }
+///
+/// async fn update(profile: Normalized<Profile>) -> String {
+///     profile.name.clone()
+/// }
+///
+/// fn main() {
+///     let mut normalizer = Normalizer::new();
+///     normalizer.set_collapse_whitespace(true);
+///
+///     App::new()
+///         .app_data(Data::new(normalizer))
+///         .route("/profile", web::put().to(update));
+/// }
+/// ```
+pub struct Normalized<T>(pub T);
+
+/// Normalized implementation
+impl<T> Normalized<T> {
+    /// Unwrap into inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Deref implementation for Normalized
+impl<T> Deref for Normalized<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// DerefMut implementation for Normalized
+impl<T> DerefMut for Normalized<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// FromRequest implementation for Normalized
+impl<T: DeserializeOwned + 'static> FromRequest for Normalized<T> {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut DevPayload) -> Self::Future {
+        let req = req.clone();
+        let value = Json::<Value>::from_request(&req, payload);
+
+        Box::pin(async move {
+            let value = value.await?.into_inner();
+            let normalizer = req.app_data::<Data<Normalizer>>()
+                .map(|item| *item.get_ref())
+                .unwrap_or_default();
+
+            stages::deserialize(&req, normalizer.normalize(value)).map(Self)
+        })
+    }
+}
@@ -0,0 +1,154 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::Errors;
+
+/// Struct container for json normalization rules, register it with `app_data` to configure `Normalized<T>`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normalizer {
+    pub trim_strings: bool,
+    pub collapse_whitespace: bool,
+    pub drop_nulls: bool,
+    pub drop_empty_strings: bool,
+    pub drop_empty_arrays: bool,
+    pub drop_empty_objects: bool,
+}
+
+/// Default implementation for Normalizer, trims strings and drops nulls and empty values
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self {
+            trim_strings: true,
+            collapse_whitespace: false,
+            drop_nulls: true,
+            drop_empty_strings: true,
+            drop_empty_arrays: true,
+            drop_empty_objects: true,
+        }
+    }
+}
+
+/// Normalizer implementation
+impl Normalizer {
+    /// Creates normalizer with the default rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set if strings are trimmed
+    pub fn set_trim_strings(&mut self, trim_strings: bool) -> &mut Self {
+        self.trim_strings = trim_strings;
+        self
+    }
+
+    /// Set if inner whitespace runs are collapsed into a single space
+    pub fn set_collapse_whitespace(&mut self, collapse_whitespace: bool) -> &mut Self {
+        self.collapse_whitespace = collapse_whitespace;
+        self
+    }
+
+    /// Set if null members and items are dropped
+    pub fn set_drop_nulls(&mut self, drop_nulls: bool) -> &mut Self {
+        self.drop_nulls = drop_nulls;
+        self
+    }
+
+    /// Set if empty strings are dropped
+    pub fn set_drop_empty_strings(&mut self, drop_empty_strings: bool) -> &mut Self {
+        self.drop_empty_strings = drop_empty_strings;
+        self
+    }
+
+    /// Set if empty arrays are dropped
+    pub fn set_drop_empty_arrays(&mut self, drop_empty_arrays: bool) -> &mut Self {
+        self.drop_empty_arrays = drop_empty_arrays;
+        self
+    }
+
+    /// Set if empty objects are dropped
+    pub fn set_drop_empty_objects(&mut self, drop_empty_objects: bool) -> &mut Self {
+        self.drop_empty_objects = drop_empty_objects;
+        self
+    }
+
+    /// Normalize value recursively, keeping key order. Dropped values become `null` at the root
+    ///
+    /// Example
+    /// ```
+    /// use library::json::Normalizer;
+    /// use serde_json::json;
+    ///
+    /// fn main() {
+    ///     let mut normalizer = Normalizer::new();
+    ///     normalizer.set_collapse_whitespace(true).set_drop_empty_arrays(false);
+    ///
+    ///     let value = normalizer.normalize(json!({ "name": "  John   Doe ", "nickname": "", "tags": [], "age": null }));
+    ///     assert_eq!(value.to_string(), r#"{"name":"John Doe","tags":[]}"#);
+    /// }
+    /// ```
+    pub fn normalize(&self, value: Value) -> Value {
+        self.normalize_value(value).unwrap_or(Value::Null)
+    }
+
+    /// Normalize value and deserialize it into T
+    pub fn normalize_into<T: DeserializeOwned>(&self, value: Value) -> Result<T, Errors> {
+        serde_json::from_value(self.normalize(value))
+            .map_err(|error| Errors::new(format!("Json deserialize error: {}", error)))
+    }
+
+    // Normalize value, returns None when the value is dropped
+    fn normalize_value(&self, value: Value) -> Option<Value> {
+        match value {
+            Value::Null => (!self.drop_nulls).then_some(Value::Null),
+            Value::String(item) => {
+                let item = match (self.collapse_whitespace, self.trim_strings) {
+                    (true, true) => item.split_whitespace().collect::<Vec<&str>>().join(" "),
+                    (true, false) => collapse(&item),
+                    (false, true) => item.trim().to_string(),
+                    (false, false) => item,
+                };
+
+                (!(self.drop_empty_strings && item.is_empty())).then_some(Value::String(item))
+            },
+            Value::Array(items) => {
+                let items: Vec<Value> = items.into_iter()
+                    .filter_map(|item| self.normalize_value(item))
+                    .collect();
+
+                (!(self.drop_empty_arrays && items.is_empty())).then_some(Value::Array(items))
+            },
+            Value::Object(members) => {
+                let mut map = Map::new();
+                for (key, item) in members {
+                    if let Some(item) = self.normalize_value(item) {
+                        map.insert(key, item);
+                    }
+                }
+
+                (!(self.drop_empty_objects && map.is_empty())).then_some(Value::Object(map))
+            },
+            item => Some(item),
+        }
+    }
+}
+
+// Collapse inner whitespace runs into a single space, keeping a single leading and trailing space
+fn collapse(item: &str) -> String {
+    let mut result = String::with_capacity(item.len());
+    let mut is_whitespace = false;
+    for c in item.chars() {
+        match c.is_whitespace() {
+            true if is_whitespace => {},
+            true => {
+                is_whitespace = true;
+                result.push(' ');
+            },
+            false => {
+                is_whitespace = false;
+                result.push(c);
+            }
+        }
+    }
+
+    result
+}
@@ -1,7 +1,12 @@
+// Lets `library-macros` expansions refer to `::library` inside this crate too
+extern crate self as library;
+
+pub mod audits;
 pub mod bases;
 pub mod catchers;
 pub mod ciphers;
 pub mod conversions;
+pub mod correlations;
 pub mod cors;
 pub mod databases;
 pub mod dates;
@@ -9,20 +14,34 @@ pub mod enums;
 pub mod envs;
 pub mod errors;
 pub mod favicons;
+pub mod firewalls;
 pub mod guards;
 pub mod handlebars;
 pub mod json;
+pub mod locales;
 pub mod macros;
 pub mod mailers;
+pub mod mimes;
+pub mod names;
 pub mod numbers;
+pub mod openapi;
 pub mod paseto;
+pub mod passwords;
+pub mod patches;
 pub mod payloads;
 pub mod placeholders;
+pub mod problems;
+pub mod redactions;
 pub mod s3;
 pub mod schedulers;
 pub mod sse;
+pub mod stages;
 pub mod strings;
+pub mod tenants;
+pub mod uploads;
 pub mod user_agent;
+pub mod validations;
+pub mod webhooks;
 pub mod ws;
 
 pub use crate::bases::Base;
@@ -31,15 +50,85 @@ pub use crate::errors::Errors;
 pub use crate::mailers::Mailer;
 pub use crate::paseto::Paseto;
 pub use crate::payloads::Payload;
+pub use crate::problems::Problem;
 pub use crate::s3::S3;
 
 pub use crate::databases::DBPool;
 pub use crate::databases::PgPool;
 pub use crate::databases::PgPooledConnection;
 
+pub use crate::audits::Audit;
+
+pub use crate::firewalls::Firewall;
+
 pub use crate::guards::Guard;
 pub use crate::guards::GuardMiddleware;
 
+pub use crate::tenants::Tenant;
+
+/// Declares a guard next to an actix handler, e.g. `#[guarded(roles = "Admin", token = "refresh", claims = Actor, callback = find_actor)]`
+///
+/// Example
+/// ```
+/// use actix_web::{get, App, HttpResponse, Responder};
+/// use actix_web::web::Data;
+/// use std::sync::{Arc, Mutex};
+/// use library::guarded;
+/// use library::guards::Options;
+/// use library::{Errors, Paseto, PgPooledConnection};
+///
+/// // Create actor struct
+/// pub struct Actor {
+///     id: String,
+/// }
+///
+/// // Retrieve actor from token
+/// fn find_actor(_conn: &mut PgPooledConnection, options: Options, _paseto: Option<Data<Arc<Mutex<Paseto>>>>) -> Result<Actor, Errors> {
+///     match options.token.is_empty() {
+///         true => Err(Errors::new("Invalid authentication token")),
+///         false => Ok(Actor { id: options.token })
+///     }
+/// }
+///
+/// #[get("/tokens/refresh")]
+/// #[guarded(roles = "Admin, User", token = "refresh", claims = Actor, callback = find_actor)]
+/// async fn refresh(actor: Actor) -> impl Responder {
+///     HttpResponse::Ok().body(actor.id)
+/// }
+///
+/// fn main() {
+///     App::new().service(refresh);
+/// }
+/// ```
+pub use library_macros::guarded;
+
+/// Derives the placeholder methods (`new`, `from`, `from_string`, `to`, `is_empty`, `normalize`) and their `Result` variants
+///
+/// Example
+/// ```
+/// use library::Placeholder;
+/// use sanitizer::prelude::*;
+/// use serde::{Serialize, Deserialize};
+///
+/// /// Struct container for invite
+/// #[derive(Debug, Clone, Default, PartialEq, Placeholder, Sanitize, Serialize, Deserialize)]
+/// pub struct Invite {
+///     #[sanitize(trim)]
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     pub email: Option<String>,
+/// }
+///
+/// fn main() {
+///     let mut invite = Invite::from_string(r#"{"email": " john@example.com "}"#);
+///     invite.normalize();
+///     assert_eq!(invite.email.as_deref(), Some("john@example.com"));
+///
+///     assert!(Invite::try_from_string("not json").is_err());
+///     assert!(Invite::new().is_empty());
+/// }
+/// ```
+pub use library_macros::Placeholder;
+
 pub use crate::enums::EnumI32;
 
 pub use crate::placeholders::Facebook;
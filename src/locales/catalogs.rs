@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::Errors;
+
+/// Struct container for localized messages, keyed by locale and then by message key
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    pub default_locale: String,
+    pub messages: HashMap<String, HashMap<String, String>>,
+}
+
+/// Default implementation for Catalog, with the built-in english, filipino and japanese messages
+impl Default for Catalog {
+    fn default() -> Self {
+        let mut catalog = Self {
+            default_locale: String::from("en"),
+            messages: HashMap::new(),
+        };
+
+        for (locale, json) in [
+            ("en", include_str!("en.json")),
+            ("fil", include_str!("fil.json")),
+            ("ja", include_str!("ja.json")),
+        ] {
+            catalog.add_messages(locale, json).expect("Invalid built-in locale file");
+        }
+
+        catalog
+    }
+}
+
+/// Catalog implementation
+impl Catalog {
+    /// Creates catalog with the built-in messages
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set locale used when no requested locale is available
+    pub fn set_default_locale<T: Into<String>>(&mut self, locale: T) -> &mut Self {
+        self.default_locale = locale.into();
+        self
+    }
+
+    /// Add or override messages of a locale from a flat json object, e.g. `{"page-not-found": "Page Not Found"}`
+    ///
+    /// Example
+    /// ```
+    /// use library::locales::Catalog;
+    ///
+    /// fn main() {
+    ///     let mut catalog = Catalog::new();
+    ///     catalog.add_messages("fil", r#"{"welcome": "Maligayang pagdating, {name}"}"#).unwrap();
+    ///
+    ///     assert_eq!(catalog.translate("fil", "welcome", &[("name", "Juan")]), "Maligayang pagdating, Juan");
+    /// }
+    /// ```
+    pub fn add_messages<L: Into<String>>(&mut self, locale: L, json: &str) -> Result<&mut Self, Errors> {
+        let messages: HashMap<String, String> = serde_json::from_str(json)
+            .map_err(|error| Errors::config("Invalid locale file").with_source(error))?;
+
+        self.messages.entry(locale.into()).or_default().extend(messages);
+        Ok(self)
+    }
+
+    /// Add messages from every `<locale>.json` file of a directory, e.g. `locales/ja.json`
+    pub fn load_directory<P: AsRef<Path>>(&mut self, path: P) -> Result<&mut Self, Errors> {
+        let entries = fs::read_dir(path)
+            .map_err(|error| Errors::config("Unable to read locale directory").with_source(error))?;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().map(|item| item != "json").unwrap_or(true) {
+                continue;
+            }
+
+            let locale = match path.file_stem().and_then(|item| item.to_str()) {
+                Some(locale) => locale.to_string(),
+                None => continue
+            };
+
+            let json = fs::read_to_string(&path)
+                .map_err(|error| Errors::config("Unable to read locale file").with_source(error))?;
+
+            self.add_messages(locale, &json)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Retrieve available locales
+    pub fn locales(&self) -> Vec<String> {
+        let mut locales: Vec<String> = self.messages.keys().cloned().collect();
+        locales.sort();
+        locales
+    }
+
+    /// Select the best available locale from an `Accept-Language` value, falls back to default locale
+    ///
+    /// Example
+    /// ```
+    /// use library::locales::Catalog;
+    ///
+    /// fn main() {
+    ///     let catalog = Catalog::new();
+    ///     assert_eq!(catalog.negotiate("de-DE, ja-JP;q=0.8, en;q=0.5"), "ja");
+    ///     assert_eq!(catalog.negotiate("de"), "en");
+    /// }
+    /// ```
+    pub fn negotiate(&self, accept_language: &str) -> String {
+        // Parse language ranges with their quality
+        let mut ranges: Vec<(String, f32)> = accept_language
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.split(';');
+                let tag = parts.next()?.trim().to_lowercase();
+                let quality = parts
+                    .find_map(|part| part.trim().strip_prefix("q=").and_then(|q| q.parse::<f32>().ok()))
+                    .unwrap_or(1.0);
+
+                (!tag.is_empty() && quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+
+        ranges.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        // Match exact tag, then primary language
+        for (tag, _) in ranges.iter() {
+            if let Some(locale) = self.messages.keys().find(|locale| locale.to_lowercase() == *tag) {
+                return locale.clone();
+            }
+
+            let primary = tag.split('-').next().unwrap_or_default();
+            if let Some(locale) = self.messages.keys().find(|locale| locale.to_lowercase() == primary) {
+                return locale.clone();
+            }
+        }
+
+        self.default_locale.clone()
+    }
+
+    /// Retrieve message of a locale, falls back to default locale
+    pub fn get(&self, locale: &str, key: &str) -> Option<&str> {
+        self.messages.get(locale)
+            .and_then(|messages| messages.get(key))
+            .or_else(|| self.messages.get(&self.default_locale).and_then(|messages| messages.get(key)))
+            .map(|message| message.as_str())
+    }
+
+    /// Retrieve message key of a message of a locale, e.g. to translate `Errors` built from catalog messages
+    ///
+    /// Example
+    /// ```
+    /// use library::locales::Catalog;
+    ///
+    /// fn main() {
+    ///     let catalog = Catalog::new();
+    ///     assert_eq!(catalog.key_of("en", "Invalid refresh token"), Some("invalid-refresh-token"));
+    ///     assert_eq!(catalog.key_of("en", "Unknown message"), None);
+    /// }
+    /// ```
+    pub fn key_of(&self, locale: &str, message: &str) -> Option<&str> {
+        self.messages.get(locale)?
+            .iter()
+            .find(|(_, item)| item.as_str() == message)
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// Translate message key replacing `{name}` arguments, falls back to the key itself
+    pub fn translate(&self, locale: &str, key: &str, args: &[(&str, &str)]) -> String {
+        let mut message = self.get(locale, key).unwrap_or(key).to_string();
+        for (name, value) in args {
+            message = message.replace(&format!("{{{}}}", name), value);
+        }
+
+        message
+    }
+}
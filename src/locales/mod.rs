@@ -0,0 +1,117 @@
+pub mod catalogs;
+
+use actix_web::HttpRequest;
+use actix_web::http::header;
+use actix_web::web::Data;
+use handlebars::{Context, Handlebars, Helper, HelperResult, Output, RenderContext};
+use std::sync::OnceLock;
+
+pub use crate::locales::catalogs::Catalog;
+
+/// Built-in catalog used when no `Data<Catalog>` is registered
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// Retrieve catalog registered with `app_data`, or the built-in catalog
+///
+/// Example
+/// ```
+/// use actix_web::App;
+/// use actix_web::web::Data;
+/// use library::locales::Catalog;
+///
+/// fn main() {
+///     let mut catalog = Catalog::new();
+///     catalog.add_messages("en", r#"{"page-not-found": "Nothing here"}"#).unwrap();
+///
+///     App::new().app_data(Data::new(catalog));
+/// }
+/// ```
+pub fn catalog(req: &HttpRequest) -> &Catalog {
+    match req.app_data::<Data<Catalog>>() {
+        Some(catalog) => catalog.get_ref(),
+        None => builtin()
+    }
+}
+
+/// Retrieve built-in catalog, for messages produced outside of a request
+pub fn builtin() -> &'static Catalog {
+    CATALOG.get_or_init(Catalog::default)
+}
+
+/// Retrieve built-in message of a key in the default locale, e.g. for `Errors` translated when responding
+///
+/// Example
+/// ```
+/// use library::{locales, Errors};
+///
+/// fn main() {
+///     let error = Errors::unauthorized(locales::message("invalid-authentication-token"));
+///     assert_eq!(error.as_str(), "Invalid authentication token");
+///     assert_eq!(error.key().as_deref(), Some("invalid-authentication-token"));
+/// }
+/// ```
+pub fn message(key: &str) -> String {
+    let catalog = builtin();
+    catalog.translate(&catalog.default_locale, key, &[])
+}
+
+/// Retrieve locale of the request from `Accept-Language` header
+///
+/// Example
+/// ```
+/// use actix_web::test::TestRequest;
+/// use library::locales;
+///
+/// fn main() {
+///     let req = TestRequest::get()
+///         .insert_header(("Accept-Language", "fil-PH, en;q=0.5"))
+///         .to_http_request();
+///
+///     assert_eq!(locales::negotiate(&req), "fil");
+/// }
+/// ```
+pub fn negotiate(req: &HttpRequest) -> String {
+    let accept_language = req.headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|item| item.to_str().ok())
+        .unwrap_or_default();
+
+    catalog(req).negotiate(accept_language)
+}
+
+/// Translate message key for the request locale
+pub fn translate(req: &HttpRequest, key: &str, args: &[(&str, &str)]) -> String {
+    catalog(req).translate(&negotiate(req), key, args)
+}
+
+/// Register `t` helper that translates a message key using the `locale` of the template data, e.g. `{{t "page-not-found"}}`
+///
+/// Example
+/// ```
+/// use handlebars::Handlebars;
+/// use library::locales::{self, Catalog};
+/// use serde_json::json;
+///
+/// fn main() {
+///     let mut hbs = Handlebars::new();
+///     locales::register_helper(&mut hbs, Catalog::new());
+///
+///     let body = hbs.render_template(r#"{{t "page-not-found"}}"#, &json!({ "locale": "ja" })).unwrap();
+///     assert_eq!(body, "ページが見つかりません");
+/// }
+/// ```
+pub fn register_helper(hbs: &mut Handlebars<'_>, catalog: Catalog) {
+    hbs.register_helper("t", Box::new(move |h: &Helper, _: &Handlebars, ctx: &Context, _: &mut RenderContext, out: &mut dyn Output| -> HelperResult {
+        let key = h.param(0).and_then(|item| item.value().as_str()).unwrap_or_default();
+        let locale = ctx.data().get("locale").and_then(|item| item.as_str()).unwrap_or(&catalog.default_locale);
+
+        // Use hash parameters as arguments, e.g. `{{t "welcome" name=user.name}}`
+        let args: Vec<(String, String)> = h.hash().iter()
+            .map(|(name, value)| (name.to_string(), value.value().as_str().map(String::from).unwrap_or_else(|| value.value().to_string())))
+            .collect();
+        let args: Vec<(&str, &str)> = args.iter().map(|(name, value)| (name.as_str(), value.as_str())).collect();
+
+        out.write(&catalog.translate(locale, key, &args))?;
+        Ok(())
+    }));
+}
@@ -3,6 +3,7 @@ use lettre::message::{header, MultiPart, SinglePart};
 use lettre::transport::smtp::authentication::Credentials;
 
 use crate::Errors;
+use crate::redactions::{Masker, Redact};
 
 /// Mailer struct contains commonly used smtp email credentials
 #[derive(Clone, Debug, PartialEq)]
@@ -27,6 +28,17 @@ impl Default for Mailer {
     }
 }
 
+/// Redact implementation for Mailer, masks the sender, username and password
+impl Redact for Mailer {
+    fn redacted(&self) -> Self {
+        Self {
+            sender: Masker::Email.mask(&self.sender),
+            username: Masker::Email.mask(&self.username),
+            password: Masker::Secret.mask(&self.password),
+            ..self.clone()
+        }
+    }
+}
 
 // Mailer implementation
 impl Mailer {
@@ -118,7 +130,7 @@ impl Mailer {
     pub fn send_mail(&self, to: &str, subject: &str, body: &str) -> Result<String, Errors> {
         // Check if self has data
         if self.is_empty() {
-            return Err(Errors::new("Your platform's email configuration is invalid. Please contact your administrator"));
+            return Err(Errors::config("Your platform's email configuration is invalid. Please contact your administrator").logged());
         }
 
         // Create multipart body
@@ -137,8 +149,8 @@ impl Mailer {
             .multipart(multipart);
 
         // If builder encounters an error
-        if builder.is_err() {
-            return Err(Errors::new(&builder.unwrap_err().to_string()));
+        if let Err(error) = builder {
+            return Err(Errors::config("Unable to build email").with_source(error).logged());
         }
 
         // Set credentials
@@ -146,8 +158,8 @@ impl Mailer {
 
         // Set smtp transport relay
         let relay = SmtpTransport::relay(self.smtp_host.as_str());
-        if relay.is_err() {
-            return Err(Errors::new(&relay.unwrap_err().to_string()));
+        if let Err(error) = relay {
+            return Err(Errors::external("Unable to connect to the mail server").with_source(error).logged());
         }
 
         // Open a remote connection
@@ -156,7 +168,7 @@ impl Mailer {
         // Send the email
         match mailer.send(&builder.unwrap()) {
             Ok(_) => Ok(format!("Email send successfully to {}", to)),
-            Err(e) => Err(Errors::new(&e.to_string())),
+            Err(e) => Err(Errors::external("Unable to send email").with_source(e).logged()),
         }
     }
 }
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Struct container for mime type categories
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    Image,
+    Video,
+    Audio,
+    Document,
+    Archive,
+    Font,
+    Text,
+    #[default]
+    Other,
+}
+
+/// Category implementation
+impl Category {
+    /// Retrieve category name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Image => "image",
+            Category::Video => "video",
+            Category::Audio => "audio",
+            Category::Document => "document",
+            Category::Archive => "archive",
+            Category::Font => "font",
+            Category::Text => "text",
+            Category::Other => "other",
+        }
+    }
+}
+
+/// Display implementation for Category
+impl Display for Category {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", self.as_str())
+    }
+}
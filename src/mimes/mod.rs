@@ -0,0 +1,189 @@
+pub mod categories;
+pub mod types;
+
+pub use crate::mimes::categories::Category;
+pub use crate::mimes::types::{Type, TYPES};
+
+/// Mime type used when a type can not be determined
+pub const DEFAULT: &str = "application/octet-stream";
+
+/// Retrieve registered type of a mime type or one of its aliases, parameters like `; charset=utf-8` are ignored
+pub fn find(mime: &str) -> Option<&'static Type> {
+    let mime = mime.split(';').next().unwrap_or_default().trim().to_lowercase();
+    TYPES.iter().find(|item| item.mime == mime || item.aliases.contains(&mime.as_str()))
+}
+
+/// Retrieve registered type of an extension, with or without the leading dot
+pub fn find_extension(extension: &str) -> Option<&'static Type> {
+    let extension = extension.trim().trim_start_matches('.').to_lowercase();
+    TYPES.iter().find(|item| item.extensions.contains(&extension.as_str()))
+}
+
+/// Retrieve canonical name of a mime type, e.g. `image/jpg` becomes `image/jpeg`. Unknown types are returned lowercased
+///
+/// Example
+/// ```
+/// use library::mimes;
+///
+/// fn main() {
+///     assert_eq!(mimes::canonical("image/JPG"), "image/jpeg");
+///     assert_eq!(mimes::canonical("audio/x-wav; codecs=1"), "audio/wav");
+///     assert_eq!(mimes::canonical("application/x-custom"), "application/x-custom");
+/// }
+/// ```
+pub fn canonical(mime: &str) -> String {
+    match find(mime) {
+        Some(item) => item.mime.to_string(),
+        None => mime.split(';').next().unwrap_or_default().trim().to_lowercase()
+    }
+}
+
+/// Retrieve mime type of an extension
+///
+/// Example
+/// ```
+/// use library::mimes;
+///
+/// fn main() {
+///     assert_eq!(mimes::from_extension(".JPEG"), Some("image/jpeg"));
+///     assert_eq!(mimes::from_extension("xlsx"), Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"));
+///     assert_eq!(mimes::from_extension("unknown"), None);
+/// }
+/// ```
+pub fn from_extension(extension: &str) -> Option<&'static str> {
+    find_extension(extension).map(|item| item.mime)
+}
+
+/// Retrieve mime type from the extension of a file name or path
+///
+/// Example
+/// ```
+/// use library::mimes;
+///
+/// fn main() {
+///     assert_eq!(mimes::from_path("uploads/report.final.PDF"), Some("application/pdf"));
+///     assert_eq!(mimes::from_path("README"), None);
+/// }
+/// ```
+pub fn from_path(path: &str) -> Option<&'static str> {
+    let file_name = path.rsplit(['/', '\\']).next().unwrap_or_default();
+    match file_name.rsplit_once('.') {
+        Some((name, extension)) if !name.is_empty() => from_extension(extension),
+        _ => None
+    }
+}
+
+/// Retrieve preferred extension (without dot) of a mime type or alias
+///
+/// Example
+/// ```
+/// use library::mimes;
+///
+/// fn main() {
+///     assert_eq!(mimes::extension("image/jpeg"), Some("jpg"));
+///     assert_eq!(mimes::extension("image/x-icon"), Some("ico"));
+/// }
+/// ```
+pub fn extension(mime: &str) -> Option<&'static str> {
+    extensions(mime).first().copied()
+}
+
+/// Retrieve all extensions of a mime type or alias
+pub fn extensions(mime: &str) -> &'static [&'static str] {
+    find(mime).map(|item| item.extensions).unwrap_or_default()
+}
+
+/// Retrieve category of a mime type, unknown types fall back to their top level type
+///
+/// Example
+/// ```
+/// use library::mimes::{self, Category};
+///
+/// fn main() {
+///     assert_eq!(mimes::category("image/svg+xml"), Category::Image);
+///     assert_eq!(mimes::category("application/x-7z-compressed"), Category::Archive);
+///     assert_eq!(mimes::category("video/x-unknown"), Category::Video);
+/// }
+/// ```
+pub fn category(mime: &str) -> Category {
+    if let Some(item) = find(mime) {
+        return item.category;
+    }
+
+    match mime.trim().to_lowercase().split('/').next().unwrap_or_default() {
+        "image" => Category::Image,
+        "video" => Category::Video,
+        "audio" => Category::Audio,
+        "font" => Category::Font,
+        "text" => Category::Text,
+        _ => Category::Other
+    }
+}
+
+/// Retrieve registered mime types of a category, e.g. for upload allowlists
+pub fn by_category(category: Category) -> Vec<&'static str> {
+    TYPES.iter().filter(|item| item.category == category).map(|item| item.mime).collect()
+}
+
+/// Check if mime type is an image
+pub fn is_image(mime: &str) -> bool {
+    category(mime) == Category::Image
+}
+
+/// Check if mime type is an image the `image` crate can decode, e.g. for thumbnails and dimensions
+pub fn is_processable_image(mime: &str) -> bool {
+    find(mime).map(|item| item.is_processable).unwrap_or_default()
+}
+
+/// Detect mime type from magic bytes, falling back to the file name extension.
+/// Extensions are only trusted for types without a signature, or to name the specific type of a
+/// generic container (a `.docx` detected as zip), so a renamed file can not pass as another type
+///
+/// Example
+/// ```
+/// use library::mimes;
+///
+/// fn main() {
+///     let png = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00];
+///
+///     assert_eq!(mimes::detect(&png, Some("avatar.jpg")), "image/png");
+///     assert_eq!(mimes::detect(b"name,email", Some("users.csv")), "text/csv");
+///     assert_eq!(mimes::detect(b"not an image", Some("avatar.png")), "application/octet-stream");
+/// }
+/// ```
+pub fn detect(bytes: &[u8], file_name: Option<&str>) -> String {
+    resolve(bytes, file_name, true)
+}
+
+/// Detect mime type from magic bytes only, for untrusted files like uploads. The file name is only used to name the
+/// specific type of a detected container when that type has its own signature (a `.docx` detected as zip), so a client
+/// can never pick a type the bytes do not prove, e.g. an svg named `avatar.svg` stays `application/xml`
+///
+/// Example
+/// ```
+/// use library::mimes;
+///
+/// fn main() {
+///     let svg = b"<?xml version=\"1.0\"?><svg xmlns=\"http://www.w3.org/2000/svg\"></svg>";
+///
+///     assert_eq!(mimes::sniff(svg, Some("avatar.svg")), "application/xml");
+///     assert_eq!(mimes::sniff(b"name,email", Some("users.csv")), "application/octet-stream");
+///     assert_eq!(mimes::sniff(&[0x00, 0x00, 0x02, 0x00], Some("image.tga")), "application/octet-stream");
+/// }
+/// ```
+pub fn sniff(bytes: &[u8], file_name: Option<&str>) -> String {
+    resolve(bytes, file_name, false)
+}
+
+// Resolve mime type from magic bytes and the file name, `is_extension_trusted` allows types without a signature
+fn resolve(bytes: &[u8], file_name: Option<&str>, is_extension_trusted: bool) -> String {
+    let extension = file_name.and_then(from_path).and_then(find).filter(|item| is_extension_trusted || item.has_signature);
+    let detected = infer::get(bytes).map(|item| item.mime_type().to_lowercase());
+
+    match (detected, extension) {
+        (Some(detected), Some(extension)) if extension.container.is_some_and(|container| container == detected || container == canonical(&detected)) => extension.mime.to_string(),
+        (Some(detected), _) => canonical(&detected),
+        (None, Some(extension)) if !extension.has_signature => extension.mime.to_string(),
+        _ => DEFAULT.to_string()
+    }
+}
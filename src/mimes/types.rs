@@ -0,0 +1,179 @@
+use crate::mimes::Category;
+use crate::mimes::Category::*;
+
+/// Struct container for a registered mime type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Type {
+    pub mime: &'static str,
+    pub extensions: &'static [&'static str],
+    pub aliases: &'static [&'static str],
+    pub category: Category,
+    pub is_processable: bool,
+    pub has_signature: bool,
+    pub container: Option<&'static str>,
+}
+
+/// Type implementation
+impl Type {
+    /// Create type, the first extension is the preferred one
+    pub const fn new(mime: &'static str, extensions: &'static [&'static str], category: Category) -> Self {
+        Self { mime, extensions, aliases: &[], category, is_processable: false, has_signature: false, container: None }
+    }
+
+    /// Set other mime names of the type, e.g. `image/jpg`
+    pub const fn aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Mark image as processable by the `image` crate (thumbnails, dimensions)
+    pub const fn processable(mut self) -> Self {
+        self.is_processable = true;
+        self
+    }
+
+    /// Mark type as detectable from its magic bytes, so a file extension alone is never trusted for it
+    pub const fn signature(mut self) -> Self {
+        self.has_signature = true;
+        self
+    }
+
+    /// Set generic type magic bytes detection reports for this type, e.g. `application/zip` for `.jar`
+    pub const fn container(mut self, container: &'static str) -> Self {
+        self.container = Some(container);
+        self
+    }
+}
+
+/// Registered mime types, a type listed first wins extension lookups shared with later types (e.g. `3gp`)
+pub static TYPES: &[Type] = &[
+    // Images
+    Type::new("image/jpeg", &["jpg", "jpeg", "jpe", "jfif"], Image).aliases(&["image/jpg", "image/pjpeg"]).processable().signature(),
+    Type::new("image/png", &["png"], Image).aliases(&["image/x-png"]).processable().signature(),
+    Type::new("image/gif", &["gif"], Image).processable().signature(),
+    Type::new("image/webp", &["webp"], Image).processable().signature(),
+    Type::new("image/bmp", &["bmp", "dib"], Image).aliases(&["image/x-bmp", "image/x-ms-bmp"]).processable().signature(),
+    Type::new("image/tiff", &["tif", "tiff"], Image).processable().signature(),
+    Type::new("image/vnd.microsoft.icon", &["ico"], Image).aliases(&["image/x-icon"]).processable().signature(),
+    Type::new("image/x-tga", &["tga"], Image).aliases(&["image/x-targa", "image/tga"]).processable(),
+    Type::new("image/svg+xml", &["svg", "svgz"], Image).container("text/xml"),
+    Type::new("image/avif", &["avif"], Image).signature(),
+    Type::new("image/heif", &["heif", "heic"], Image).aliases(&["image/heic"]).signature(),
+    Type::new("image/jxl", &["jxl"], Image).signature(),
+    Type::new("image/jp2", &["jp2"], Image).signature(),
+    Type::new("image/vnd.ms-photo", &["jxr"], Image).signature(),
+    Type::new("image/vnd.adobe.photoshop", &["psd"], Image).aliases(&["image/x-photoshop"]).signature(),
+    Type::new("image/x-canon-cr2", &["cr2"], Image).signature(),
+    Type::new("image/openraster", &["ora"], Image).signature(),
+
+    // Videos
+    Type::new("video/mp4", &["mp4"], Video).signature(),
+    Type::new("video/x-m4v", &["m4v"], Video).signature(),
+    Type::new("video/mpeg", &["mpeg", "mpg"], Video).signature(),
+    Type::new("video/quicktime", &["mov", "qt"], Video).signature(),
+    Type::new("video/x-msvideo", &["avi"], Video).aliases(&["video/avi", "video/msvideo"]).signature(),
+    Type::new("video/webm", &["webm"], Video).signature(),
+    Type::new("video/x-matroska", &["mkv"], Video).signature(),
+    Type::new("video/x-flv", &["flv"], Video).signature(),
+    Type::new("video/x-ms-wmv", &["wmv"], Video).signature(),
+    Type::new("video/ogg", &["ogv"], Video).container("audio/ogg").signature(),
+    Type::new("video/mp2t", &["ts", "m2ts"], Video),
+    Type::new("video/3gpp", &["3gp"], Video),
+    Type::new("video/3gpp2", &["3g2"], Video).aliases(&["video/3g2"]),
+
+    // Audio
+    Type::new("audio/mpeg", &["mp3"], Audio).aliases(&["audio/mp3"]).signature(),
+    Type::new("audio/aac", &["aac"], Audio).signature(),
+    Type::new("audio/wav", &["wav"], Audio).aliases(&["audio/x-wav", "audio/wave", "audio/vnd.wave"]).signature(),
+    Type::new("audio/ogg", &["oga", "ogg"], Audio).signature(),
+    Type::new("audio/opus", &["opus"], Audio).container("audio/ogg").signature(),
+    Type::new("audio/flac", &["flac"], Audio).aliases(&["audio/x-flac"]).signature(),
+    Type::new("audio/midi", &["mid", "midi"], Audio).aliases(&["audio/x-midi"]).signature(),
+    Type::new("audio/mp4", &["m4a"], Audio).aliases(&["audio/m4a", "audio/x-m4a"]).signature(),
+    Type::new("audio/amr", &["amr"], Audio).signature(),
+    Type::new("audio/aiff", &["aif", "aiff"], Audio).aliases(&["audio/x-aiff"]).signature(),
+    Type::new("audio/x-ape", &["ape"], Audio).signature(),
+    Type::new("audio/x-dsf", &["dsf"], Audio).signature(),
+    Type::new("audio/webm", &["weba"], Audio).container("video/webm").signature(),
+    Type::new("audio/3gpp", &["3gp"], Audio),
+    Type::new("audio/3gpp2", &["3g2"], Audio).aliases(&["audio/3g2"]),
+
+    // Documents
+    Type::new("application/pdf", &["pdf"], Document).signature(),
+    Type::new("application/msword", &["doc", "dot"], Document).container("application/x-ole-storage").signature(),
+    Type::new("application/vnd.openxmlformats-officedocument.wordprocessingml.document", &["docx"], Document).container("application/zip").signature(),
+    Type::new("application/vnd.ms-excel", &["xls", "xlt"], Document).container("application/x-ole-storage").signature(),
+    Type::new("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", &["xlsx"], Document).container("application/zip").signature(),
+    Type::new("application/vnd.ms-powerpoint", &["ppt", "pps", "pot"], Document).container("application/x-ole-storage").signature(),
+    Type::new("application/vnd.openxmlformats-officedocument.presentationml.presentation", &["pptx"], Document).container("application/zip").signature(),
+    Type::new("application/vnd.oasis.opendocument.text", &["odt"], Document).container("application/zip").signature(),
+    Type::new("application/vnd.oasis.opendocument.spreadsheet", &["ods"], Document).container("application/zip").signature(),
+    Type::new("application/vnd.oasis.opendocument.presentation", &["odp"], Document).container("application/zip").signature(),
+    Type::new("application/vnd.visio", &["vsd"], Document).container("application/x-ole-storage").signature(),
+    Type::new("application/rtf", &["rtf"], Document).aliases(&["text/rtf"]).signature(),
+    Type::new("application/epub+zip", &["epub"], Document).container("application/zip").signature(),
+    Type::new("application/x-mobipocket-ebook", &["mobi"], Document).signature(),
+    Type::new("application/vnd.amazon.ebook", &["azw"], Document),
+    Type::new("application/x-abiword", &["abw"], Document),
+    Type::new("application/postscript", &["ps", "eps", "ai"], Document).signature(),
+    Type::new("text/plain", &["txt", "text", "log"], Document),
+    Type::new("text/csv", &["csv"], Document),
+    Type::new("text/markdown", &["md", "markdown"], Document),
+
+    // Archives
+    Type::new("application/zip", &["zip"], Archive).aliases(&["application/x-zip-compressed"]).signature(),
+    Type::new("application/gzip", &["gz", "tgz"], Archive).aliases(&["application/x-gzip"]).signature(),
+    Type::new("application/x-bzip2", &["bz2"], Archive).signature(),
+    Type::new("application/x-bzip", &["bz"], Archive),
+    Type::new("application/x-tar", &["tar"], Archive).signature(),
+    Type::new("application/vnd.rar", &["rar"], Archive).aliases(&["application/x-rar-compressed", "application/x-rar"]).signature(),
+    Type::new("application/x-7z-compressed", &["7z"], Archive).signature(),
+    Type::new("application/x-xz", &["xz"], Archive).signature(),
+    Type::new("application/zstd", &["zst"], Archive).signature(),
+    Type::new("application/x-lzip", &["lz"], Archive).signature(),
+    Type::new("application/x-compress", &["z"], Archive).signature(),
+    Type::new("application/x-cpio", &["cpio"], Archive).signature(),
+    Type::new("application/x-unix-archive", &["ar"], Archive).signature(),
+    Type::new("application/x-freearc", &["arc"], Archive),
+    Type::new("application/vnd.ms-cab-compressed", &["cab"], Archive).signature(),
+    Type::new("application/java-archive", &["jar"], Archive).container("application/zip").signature(),
+    Type::new("application/vnd.android.package-archive", &["apk"], Archive).container("application/zip").signature(),
+    Type::new("application/vnd.debian.binary-package", &["deb"], Archive).signature(),
+    Type::new("application/x-rpm", &["rpm"], Archive).signature(),
+
+    // Fonts
+    Type::new("font/ttf", &["ttf"], Font).container("application/font-sfnt").signature(),
+    Type::new("font/otf", &["otf"], Font).container("application/font-sfnt").signature(),
+    Type::new("font/woff", &["woff"], Font).container("application/font-woff").signature(),
+    Type::new("font/woff2", &["woff2"], Font).container("application/font-woff").signature(),
+    Type::new("application/vnd.ms-fontobject", &["eot"], Font).container("application/octet-stream").signature(),
+
+    // Text
+    Type::new("text/html", &["html", "htm"], Text),
+    Type::new("text/css", &["css"], Text),
+    Type::new("text/javascript", &["js", "mjs"], Text).aliases(&["application/javascript", "application/x-javascript"]),
+    Type::new("application/json", &["json"], Text),
+    Type::new("application/ld+json", &["jsonld"], Text),
+    Type::new("application/xml", &["xml"], Text).aliases(&["text/xml"]),
+    Type::new("application/xhtml+xml", &["xhtml"], Text).container("text/xml"),
+    Type::new("application/vnd.mozilla.xul+xml", &["xul"], Text).container("text/xml"),
+    Type::new("text/calendar", &["ics"], Text),
+    Type::new("application/x-httpd-php", &["php"], Text),
+    Type::new("application/x-sh", &["sh"], Text).aliases(&["text/x-shellscript"]),
+    Type::new("application/x-csh", &["csh"], Text),
+
+    // Others
+    Type::new("application/octet-stream", &["bin"], Other),
+    Type::new("application/ogg", &["ogx"], Other).container("audio/ogg").signature(),
+    Type::new("application/vnd.apple.installer+xml", &["mpkg"], Other),
+    Type::new("application/x-shockwave-flash", &["swf"], Other).signature(),
+    Type::new("application/wasm", &["wasm"], Other).signature(),
+    Type::new("application/vnd.microsoft.portable-executable", &["exe", "dll"], Other).aliases(&["application/x-msdownload"]).signature(),
+    Type::new("application/x-msi", &["msi"], Other).container("application/x-ole-storage").signature(),
+    Type::new("application/x-executable", &["elf", "o"], Other).signature(),
+    Type::new("application/x-mach-binary", &["mach"], Other).signature(),
+    Type::new("application/java", &["class"], Other).signature(),
+    Type::new("application/vnd.sqlite3", &["sqlite", "db"], Other).aliases(&["application/x-sqlite3"]).signature(),
+    Type::new("application/x-x509-ca-cert", &["der", "pem", "crt"], Other).signature(),
+    Type::new("application/dicom", &["dcm"], Other).signature(),
+];
@@ -0,0 +1,181 @@
+pub mod names;
+pub mod rules;
+
+pub use crate::names::names::Name;
+pub use crate::names::rules::Rules;
+
+/// Normalize person name with the default rules, see `normalize_with`
+///
+/// Example
+/// ```
+/// use library::names;
+///
+/// fn main() {
+///     assert_eq!(names::normalize("JOHN o’brien-MCDONALD jr."), "John O'Brien-McDonald Jr");
+///     assert_eq!(names::normalize("louis ix"), "Louis IX");
+///     assert_eq!(names::normalize("jan VAN DER berg"), "Jan van der Berg");
+/// }
+/// ```
+pub fn normalize(value: &str) -> String {
+    normalize_with(value, &Rules::default())
+}
+
+/// Normalize person name casing, particles, apostrophes, hyphens and suffixes.
+/// Names written family first (`Dela Cruz, Juan`) are returned in display order
+///
+/// Example
+/// ```
+/// use library::names::{self, Rules};
+///
+/// fn main() {
+///     assert_eq!(names::normalize_with("maria DELA CRUZ", &Rules::filipino()), "Maria Dela Cruz");
+///     assert_eq!(names::normalize_with("JUAN DE LA CRUZ Y MACHADO", &Rules::spanish()), "Juan de la Cruz y Machado");
+/// }
+/// ```
+pub fn normalize_with(value: &str, rules: &Rules) -> String {
+    parse_with(value, rules).full()
+}
+
+/// Split person name into parts with the default rules, see `parse_with`
+pub fn parse(value: &str) -> Name {
+    parse_with(value, &Rules::default())
+}
+
+/// Split normalized person name into given, middle, family and suffix parts.
+/// Particles stay with the family name they precede, and names written family first (`Dela Cruz, Juan Santos`) are supported
+///
+/// Example
+/// ```
+/// use library::names::{self, Rules};
+///
+/// fn main() {
+///     let name = names::parse_with("juan carlos santos dela cruz iii", &Rules::filipino());
+///     assert_eq!(name.given.as_deref(), Some("Juan Carlos"));
+///     assert_eq!(name.middle.as_deref(), Some("Santos"));
+///     assert_eq!(name.family.as_deref(), Some("Dela Cruz"));
+///     assert_eq!(name.suffix.as_deref(), Some("III"));
+///
+///     let name = names::parse_with("García Márquez, Gabriel José", &Rules::spanish());
+///     assert_eq!(name.family_first(), "García Márquez, Gabriel José");
+///
+///     let name = names::parse("Mary Anne de la Cruz");
+///     assert_eq!(name.given.as_deref(), Some("Mary"));
+///     assert_eq!(name.middle.as_deref(), Some("Anne"));
+///     assert_eq!(name.family.as_deref(), Some("de la Cruz"));
+/// }
+/// ```
+pub fn parse_with(value: &str, rules: &Rules) -> Name {
+    let value = value.replace(['\u{2018}', '\u{2019}', '`'], "'");
+    let mut segments: Vec<Vec<&str>> = value.split(',')
+        .map(|segment| segment.split_whitespace().filter(|word| word.chars().any(char::is_alphanumeric)).collect::<Vec<&str>>())
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    // Take trailing suffixes, e.g. `John Smith Jr.` or `Smith, John, III`
+    let total: usize = segments.iter().map(Vec::len).sum();
+    let mut suffixes = Vec::new();
+    while total - suffixes.len() > 1 {
+        let suffix = match segments.last().and_then(|segment| segment.last()) {
+            Some(word) => rules.suffix(word, total > 2),
+            None => None
+        };
+
+        let Some(suffix) = suffix else { break };
+        suffixes.insert(0, suffix);
+        if let Some(segment) = segments.last_mut() {
+            segment.pop();
+            if segment.is_empty() {
+                segments.pop();
+            }
+        }
+    }
+
+    let suffix = Some(suffixes.join(" ")).filter(|item| !item.is_empty());
+    let (family, rest) = match segments.len() {
+        0 => return Name { suffix, ..Name::default() },
+        1 => {
+            let mut units = units(&segments[0], rules, true);
+            let count = rules.family_names.min(units.len().saturating_sub(1));
+            let family = units.split_off(units.len() - count);
+            (family, units)
+        },
+        _ => {
+            let rest: Vec<&str> = segments[1..].concat();
+            (units(&segments[0], rules, false), units(&rest, rules, true))
+        }
+    };
+
+    // Filipino middle names are the maternal surname right before the family name
+    let at = match rules.middle_surname && rest.len() >= 2 {
+        true => rest.len() - 1,
+        false => rest.len().min(1)
+    };
+
+    Name {
+        given: join(&rest[..at]),
+        middle: join(&rest[at..]),
+        family: join(&family),
+        suffix,
+    }
+}
+
+// Join name units into a part
+fn join(units: &[String]) -> Option<String> {
+    Some(units.join(" ")).filter(|item| !item.is_empty())
+}
+
+// Normalize words and group particles with the word they precede, e.g. `de la Cruz`. Particles are capitalized only when leading the name
+fn units(words: &[&str], rules: &Rules, is_leading: bool) -> Vec<String> {
+    let mut units = Vec::new();
+    let mut current = Vec::new();
+
+    for (index, word) in words.iter().enumerate() {
+        let lowercase = word.to_lowercase();
+        let is_particle = rules.is_particle(&lowercase) && index + 1 < words.len();
+
+        current.push(match is_particle && (index > 0 || !is_leading) && rules.lowercase_particles {
+            true => lowercase,
+            false => case_word(word, rules)
+        });
+
+        if !is_particle {
+            units.push(current.join(" "));
+            current.clear();
+        }
+    }
+
+    units
+}
+
+// Normalize casing of each part of a hyphenated word
+fn case_word(word: &str, rules: &Rules) -> String {
+    word.split('-')
+        .map(|part| case_part(part, rules))
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+// Capitalize word, keeping prefixes like `Mc` and `O'` apart from the rest
+fn case_part(part: &str, rules: &Rules) -> String {
+    let lowercase = part.to_lowercase();
+    if rules.exceptions.iter().any(|item| lowercase.starts_with(item.as_str())) {
+        return capitalize(&lowercase);
+    }
+
+    let prefixed = rules.prefixes.iter().find_map(|prefix| {
+        lowercase.strip_prefix(prefix.as_str())
+            .filter(|rest| rest.chars().count() >= 2 && rest.chars().all(char::is_alphabetic))
+            .map(|rest| format!("{}{}", capitalize(prefix), capitalize(rest)))
+    });
+
+    prefixed.unwrap_or_else(|| capitalize(&lowercase))
+}
+
+// Uppercase first character
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new()
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Struct container for the parts of a person name
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Name {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+}
+
+/// Name implementation
+impl Name {
+    /// Check if name has no parts
+    pub fn is_empty(&self) -> bool {
+        self.given.is_none() && self.middle.is_none() && self.family.is_none() && self.suffix.is_none()
+    }
+
+    /// Retrieve name in display order, e.g. `Juan Santos Dela Cruz Jr`
+    pub fn full(&self) -> String {
+        [&self.given, &self.middle, &self.family, &self.suffix].iter()
+            .filter_map(|item| item.as_deref())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Retrieve name in sorting order, e.g. `Dela Cruz, Juan Santos Jr`
+    pub fn family_first(&self) -> String {
+        let rest = Name { given: self.given.clone(), middle: self.middle.clone(), family: None, suffix: self.suffix.clone() }.full();
+
+        match (&self.family, rest.is_empty()) {
+            (Some(family), false) => format!("{}, {}", family, rest),
+            (Some(family), true) => family.clone(),
+            (None, _) => rest
+        }
+    }
+}
+
+/// Display implementation for Name
+impl Display for Name {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", self.full())
+    }
+}
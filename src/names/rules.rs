@@ -0,0 +1,185 @@
+use std::collections::{HashMap, HashSet};
+
+/// Particles shared by most european family names, e.g. `van der Berg`, `de la Cruz`
+const PARTICLES: &[&str] = &["bin", "binti", "da", "das", "de", "del", "della", "den", "der", "di", "dos", "du", "la", "las", "le", "los", "ten", "ter", "van", "von", "y"];
+
+/// Particles of hispanic family names, `y` joins paternal and maternal surnames
+const SPANISH_PARTICLES: &[&str] = &["da", "das", "de", "del", "dos", "la", "las", "los", "y"];
+
+/// Particles of filipino family names, including the joined `dela` and `delos` forms
+const FILIPINO_PARTICLES: &[&str] = &["de", "del", "dela", "delas", "delos", "la", "las", "los", "y"];
+
+/// Words starting with `mac` that are not gaelic, e.g. `Machado`, `Macias`, `Macapagal`
+const EXCEPTIONS: &[&str] = &["maca", "mace", "mach", "maci", "mack", "maco", "macr", "macu", "macy"];
+
+/// Prefixes capitalized apart from the rest of the word
+const PREFIXES: &[&str] = &["mc", "mac", "o'", "d'"];
+
+/// Prefixes without `mac`, which is rarely gaelic in hispanic and filipino names (`Macapagal`, `Macias`)
+const HISPANIC_PREFIXES: &[&str] = &["mc", "o'", "d'"];
+
+/// Roman numerals that are also names
+const AMBIGUOUS: &[&str] = &["VI", "XI"];
+
+/// Struct container for name normalization rules, use `Rules::for_locale` for locale specific rule sets
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rules {
+    pub particles: HashSet<String>,
+    pub lowercase_particles: bool,
+    pub prefixes: Vec<String>,
+    pub exceptions: Vec<String>,
+    pub suffixes: HashMap<String, String>,
+    pub family_names: usize,
+    pub middle_surname: bool,
+}
+
+/// Default implementation for Rules, european particles in lowercase with `Mc`, `Mac`, `O'` and `D'` prefixes
+impl Default for Rules {
+    fn default() -> Self {
+        let suffixes = [("jr", "Jr"), ("jr.", "Jr"), ("sr", "Sr"), ("sr.", "Sr")];
+
+        Self {
+            particles: collect(PARTICLES),
+            lowercase_particles: true,
+            prefixes: collect(PREFIXES),
+            exceptions: collect(EXCEPTIONS),
+            suffixes: suffixes.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+            family_names: 1,
+            middle_surname: false,
+        }
+    }
+}
+
+/// Rules implementation
+impl Rules {
+    /// Create rules with the default rule set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create hispanic rules, lowercase particles and two family names (paternal and maternal), e.g. `Gabriel García Márquez`
+    pub fn spanish() -> Self {
+        Self {
+            particles: collect(SPANISH_PARTICLES),
+            prefixes: collect(HISPANIC_PREFIXES),
+            family_names: 2,
+            ..Self::default()
+        }
+    }
+
+    /// Create filipino rules, capitalized particles (`Dela Cruz`, `De Guzman`) and the middle name being the maternal surname
+    pub fn filipino() -> Self {
+        Self {
+            particles: collect(FILIPINO_PARTICLES),
+            lowercase_particles: false,
+            prefixes: collect(HISPANIC_PREFIXES),
+            middle_surname: true,
+            ..Self::default()
+        }
+    }
+
+    /// Retrieve rules of a locale, e.g. `fil-PH` or `es-MX`. Unknown locales use the default rules
+    ///
+    /// Example
+    /// ```
+    /// use library::names::Rules;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Rules::for_locale("fil-PH"), Rules::filipino());
+    ///     assert_eq!(Rules::for_locale("es_MX"), Rules::spanish());
+    ///     assert_eq!(Rules::for_locale("en"), Rules::new());
+    /// }
+    /// ```
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+
+        match language.as_str() {
+            "fil" | "tl" => Self::filipino(),
+            "es" => Self::spanish(),
+            _ => Self::default()
+        }
+    }
+
+    /// Add particles joined to the following family name, e.g. `van`
+    pub fn add_particles<T: Into<String>>(&mut self, particles: Vec<T>) -> &mut Self {
+        self.particles.extend(particles.into_iter().map(|item| item.into().to_lowercase()));
+        self
+    }
+
+    /// Set if particles after the first word are lowercased (`de la Cruz`) or capitalized (`De La Cruz`)
+    pub fn set_lowercase_particles(&mut self, lowercase_particles: bool) -> &mut Self {
+        self.lowercase_particles = lowercase_particles;
+        self
+    }
+
+    /// Add prefixes capitalized apart from the rest of the word, e.g. `mc` for `McDonald`
+    pub fn add_prefixes<T: Into<String>>(&mut self, prefixes: Vec<T>) -> &mut Self {
+        self.prefixes.extend(prefixes.into_iter().map(|item| item.into().to_lowercase()));
+        self
+    }
+
+    /// Add word beginnings never split by prefixes, e.g. `macha` keeps `Machado`
+    pub fn add_exceptions<T: Into<String>>(&mut self, exceptions: Vec<T>) -> &mut Self {
+        self.exceptions.extend(exceptions.into_iter().map(|item| item.into().to_lowercase()));
+        self
+    }
+
+    /// Add suffix and its normalized form, e.g. `esq.` to `Esq`
+    pub fn add_suffix<K: Into<String>, V: Into<String>>(&mut self, suffix: K, normalized: V) -> &mut Self {
+        self.suffixes.insert(suffix.into().to_lowercase(), normalized.into());
+        self
+    }
+
+    /// Set number of family names at the end of a name, e.g. 2 for hispanic paternal and maternal surnames
+    pub fn set_family_names(&mut self, family_names: usize) -> &mut Self {
+        self.family_names = family_names.max(1);
+        self
+    }
+
+    /// Set if the middle name is a single surname (the maternal one) before the family name, as in filipino names
+    pub fn set_middle_surname(&mut self, middle_surname: bool) -> &mut Self {
+        self.middle_surname = middle_surname;
+        self
+    }
+
+    /// Check if lowercased word is a particle
+    pub fn is_particle(&self, word: &str) -> bool {
+        self.particles.contains(word)
+    }
+
+    /// Retrieve normalized suffix of a word, e.g. roman numerals II to XXXIX.
+    /// Numerals that are also names (`Xi`, `Vi`) are only suffixes when `is_ambiguous_allowed`
+    pub fn suffix(&self, word: &str, is_ambiguous_allowed: bool) -> Option<String> {
+        let word = word.to_lowercase();
+        if let Some(suffix) = self.suffixes.get(&word) {
+            return Some(suffix.clone());
+        }
+
+        let numeral = word.to_uppercase();
+        if !is_ambiguous_allowed && AMBIGUOUS.contains(&numeral.as_str()) {
+            return None;
+        }
+
+        (2..40).any(|number| roman(number) == numeral).then_some(numeral)
+    }
+}
+
+// Collect words into owned strings
+fn collect<T: FromIterator<String>>(items: &[&str]) -> T {
+    items.iter().map(|item| item.to_string()).collect()
+}
+
+// Convert number into roman numerals
+fn roman(mut number: usize) -> String {
+    let numerals = [(10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")];
+    let mut value = String::new();
+
+    for (amount, numeral) in numerals {
+        while number >= amount {
+            value.push_str(numeral);
+            number -= amount;
+        }
+    }
+
+    value
+}
@@ -0,0 +1,133 @@
+use actix_web::{HttpResponse, Resource, web};
+use serde_json::{json, Map, Value};
+
+use crate::{File, Payload, Problem, Token};
+use crate::openapi::{schemas, Route, Schema, Security};
+use crate::user_agent::UserAgent;
+
+/// Struct container for an OpenAPI 3 document
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub title: String,
+    pub version: String,
+    pub description: String,
+    pub servers: Vec<String>,
+    pub routes: Vec<Route>,
+    pub schemas: Map<String, Value>,
+}
+
+/// Document implementation
+impl Document {
+    /// Creates document with the crate schemas and security schemes
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::App;
+    /// use library::openapi::{Document, Route};
+    ///
+    /// fn main() {
+    ///     let mut route = Route::new("post", "/tokens");
+    ///     route.set_summary("Sign in").set_response("Token");
+    ///
+    ///     let mut document = Document::new("Accounts", "1.0.0");
+    ///     document.add_route(route);
+    ///
+    ///     let json = document.to_json();
+    ///     assert!(json["paths"]["/tokens"]["post"].is_object());
+    ///
+    ///     App::new().service(document.resource("/openapi.json"));
+    /// }
+    /// ```
+    pub fn new<T: Into<String>, V: Into<String>>(title: T, version: V) -> Self {
+        let mut document = Self {
+            title: title.into(),
+            version: version.into(),
+            description: String::default(),
+            servers: vec![],
+            routes: vec![],
+            schemas: Map::new(),
+        };
+
+        document.set_schema("FieldErrors", schemas::field_errors());
+        document
+            .add_schema::<Payload>()
+            .add_schema::<Problem>()
+            .add_schema::<Token>()
+            .add_schema::<File>()
+            .add_schema::<UserAgent>();
+
+        document
+    }
+
+    /// Set description
+    pub fn set_description<T: Into<String>>(&mut self, description: T) -> &mut Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Add server url
+    pub fn add_server<T: Into<String>>(&mut self, url: T) -> &mut Self {
+        self.servers.push(url.into());
+        self
+    }
+
+    /// Add route
+    pub fn add_route(&mut self, route: Route) -> &mut Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Add schema of a type
+    pub fn add_schema<T: Schema>(&mut self) -> &mut Self {
+        self.set_schema(T::name(), T::schema())
+    }
+
+    /// Set schema by name, e.g. for app specific request bodies
+    pub fn set_schema<T: Into<String>>(&mut self, name: T, schema: Value) -> &mut Self {
+        self.schemas.insert(name.into(), schema);
+        self
+    }
+
+    /// Generate OpenAPI json
+    pub fn to_json(&self) -> Value {
+        let mut paths = Map::new();
+        for route in self.routes.iter() {
+            let item = paths.entry(route.openapi_path()).or_insert_with(|| json!({}));
+            item[route.method.as_str()] = route.operation();
+        }
+
+        let mut info = json!({ "title": self.title, "version": self.version });
+        if !self.description.is_empty() {
+            info["description"] = json!(self.description);
+        }
+
+        let mut document = json!({
+            "openapi": "3.0.3",
+            "info": info,
+            "paths": paths,
+            "components": {
+                "schemas": self.schemas,
+                "securitySchemes": Security::schemes(),
+            },
+        });
+
+        if !self.servers.is_empty() {
+            document["servers"] = json!(self.servers.iter().map(|url| json!({ "url": url })).collect::<Vec<Value>>());
+        }
+
+        document
+    }
+
+    /// Create resource serving the generated document, e.g. on `/openapi.json`
+    pub fn resource<T: Into<String>>(&self, path: T) -> Resource {
+        let body = self.to_json().to_string();
+        web::resource(path.into()).route(web::get().to(move || {
+            let body = body.clone();
+            async move {
+                HttpResponse::Ok()
+                    .content_type("application/json")
+                    .body(body)
+            }
+        }))
+    }
+}
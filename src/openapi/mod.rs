@@ -0,0 +1,9 @@
+pub mod documents;
+pub mod routes;
+pub mod schemas;
+pub mod securities;
+
+pub use crate::openapi::documents::Document;
+pub use crate::openapi::routes::Route;
+pub use crate::openapi::schemas::Schema;
+pub use crate::openapi::securities::Security;
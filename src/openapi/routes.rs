@@ -0,0 +1,195 @@
+use serde_json::{json, Map, Value};
+
+use crate::Guard;
+use crate::openapi::Security;
+
+/// Struct container for a documented route
+#[derive(Debug, Clone, Default)]
+pub struct Route {
+    pub method: String,
+    pub path: String,
+    pub summary: String,
+    pub tags: Vec<String>,
+    pub security: Security,
+    pub roles: Vec<String>,
+    pub request: Option<String>,
+    pub response: Option<String>,
+}
+
+/// Route implementation
+impl Route {
+    /// Creates public route, e.g. `Route::new("get", "/users/{id}")`
+    pub fn new<M: Into<String>, P: Into<String>>(method: M, path: P) -> Self {
+        Self {
+            method: method.into().to_lowercase(),
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates route secured by the guard mode and roles of the guard
+    ///
+    /// Example
+    /// ```
+    /// use library::Guard;
+    /// use library::openapi::{Route, Security};
+    ///
+    /// pub struct Actor {
+    ///     id: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     let guard = Guard::<Actor>::roles(vec![String::from("Admin")], None);
+    ///     let route = Route::guarded("delete", "/users/{id}", &guard);
+    ///
+    ///     assert_eq!(route.security, Security::Access);
+    ///     assert_eq!(route.roles, vec!["Admin"]);
+    /// }
+    /// ```
+    pub fn guarded<M, P, T>(method: M, path: P, guard: &Guard<T>) -> Self
+        where M: Into<String>,
+              P: Into<String>
+    {
+        let mut route = Self::new(method, path);
+        route.security = Security::from(guard);
+        route.roles = guard.roles.clone().unwrap_or_default();
+        route
+    }
+
+    /// Set summary
+    pub fn set_summary<T: Into<String>>(&mut self, summary: T) -> &mut Self {
+        self.summary = summary.into();
+        self
+    }
+
+    /// Add tag
+    pub fn add_tag<T: Into<String>>(&mut self, tag: T) -> &mut Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Set security
+    pub fn set_security(&mut self, security: Security) -> &mut Self {
+        self.security = security;
+        self
+    }
+
+    /// Set request body schema name, e.g. `Token`
+    pub fn set_request<T: Into<String>>(&mut self, schema: T) -> &mut Self {
+        self.request = Some(schema.into());
+        self
+    }
+
+    /// Set schema name of the `data` field of the response payload, e.g. `File`
+    pub fn set_response<T: Into<String>>(&mut self, schema: T) -> &mut Self {
+        self.response = Some(schema.into());
+        self
+    }
+
+    /// Retrieve OpenAPI path, e.g. `/users/{id:\d+}` becomes `/users/{id}`
+    ///
+    /// Example
+    /// ```
+    /// use library::openapi::Route;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Route::new("get", r"/users/{id:\d+}/files/{file}").openapi_path(), "/users/{id}/files/{file}");
+    /// }
+    /// ```
+    pub fn openapi_path(&self) -> String {
+        let mut path = String::new();
+        let mut is_parameter = false;
+        let mut is_pattern = false;
+        for c in self.path.chars() {
+            match c {
+                '{' => is_parameter = true,
+                ':' if is_parameter => is_pattern = true,
+                '}' => {
+                    is_parameter = false;
+                    is_pattern = false;
+                },
+                _ => {}
+            }
+
+            if !is_pattern || c == '}' {
+                path.push(c);
+            }
+        }
+
+        path
+    }
+
+    /// Retrieve OpenAPI operation object
+    pub fn operation(&self) -> Value {
+        let mut operation = Map::new();
+        if !self.summary.is_empty() {
+            operation.insert(String::from("summary"), json!(self.summary));
+        }
+
+        if !self.tags.is_empty() {
+            operation.insert(String::from("tags"), json!(self.tags));
+        }
+
+        // Set path parameters
+        let parameters: Vec<Value> = self.openapi_path()
+            .split('{')
+            .skip(1)
+            .filter_map(|item| item.split('}').next())
+            .map(|name| json!({ "name": name, "in": "path", "required": true, "schema": { "type": "string" } }))
+            .collect();
+
+        if !parameters.is_empty() {
+            operation.insert(String::from("parameters"), json!(parameters));
+        }
+
+        // Set request body
+        if let Some(request) = self.request.as_ref() {
+            operation.insert(String::from("requestBody"), json!({
+                "required": true,
+                "content": { "application/json": { "schema": { "$ref": format!("#/components/schemas/{}", request) } } },
+            }));
+        }
+
+        // Set responses
+        let success = match self.response.as_ref() {
+            Some(response) => json!({ "allOf": [
+                { "$ref": "#/components/schemas/Payload" },
+                { "type": "object", "properties": { "data": { "$ref": format!("#/components/schemas/{}", response) } } },
+            ] }),
+            None => json!({ "$ref": "#/components/schemas/Payload" }),
+        };
+
+        let mut responses = Map::new();
+        responses.insert(String::from("200"), json!({ "description": "Success", "content": { "application/json": { "schema": success } } }));
+        responses.insert(String::from("400"), error("Bad request"));
+        if self.request.is_some() {
+            responses.insert(String::from("422"), error("Validation failed, see `errors`"));
+        }
+
+        // Set security
+        if let Some(requirement) = self.security.requirement() {
+            operation.insert(String::from("security"), requirement);
+            responses.insert(String::from("401"), error("Invalid or expired token"));
+            responses.insert(String::from("403"), error("Not allowed"));
+        }
+
+        if !self.roles.is_empty() {
+            operation.insert(String::from("x-roles"), json!(self.roles));
+            operation.insert(String::from("description"), json!(format!("Roles: {}", self.roles.join(", "))));
+        }
+
+        operation.insert(String::from("responses"), Value::Object(responses));
+        Value::Object(operation)
+    }
+}
+
+/// Error response, either a json payload or Problem Details
+fn error(description: &str) -> Value {
+    json!({
+        "description": description,
+        "content": {
+            "application/json": { "schema": { "$ref": "#/components/schemas/Payload" } },
+            "application/problem+json": { "schema": { "$ref": "#/components/schemas/Problem" } },
+        },
+    })
+}
@@ -0,0 +1,161 @@
+use serde_json::{json, Value};
+
+use crate::{File, Payload, Problem, Token};
+use crate::user_agent::UserAgent;
+
+/// Schema trait, describes a type as an OpenAPI schema component
+pub trait Schema {
+    /// Component name, e.g. `Payload`
+    fn name() -> String;
+
+    /// OpenAPI schema object
+    fn schema() -> Value;
+}
+
+/// Nullable string property
+fn string() -> Value {
+    json!({ "type": "string", "nullable": true })
+}
+
+/// Nullable integer or string property, matching `EnumI32`
+fn number() -> Value {
+    json!({ "oneOf": [{ "type": "integer", "format": "int32" }, { "type": "string" }], "nullable": true })
+}
+
+/// Messages per field path, e.g. `{"items[2].price": ["Price must be positive"]}`
+pub fn field_errors() -> Value {
+    json!({
+        "type": "object",
+        "additionalProperties": { "type": "array", "items": { "type": "string" } },
+        "example": { "email": ["Email is invalid"] },
+    })
+}
+
+/// Schema implementation for Payload
+impl Schema for Payload {
+    fn name() -> String {
+        String::from("Payload")
+    }
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "code": { "type": "integer", "format": "int32" },
+                "challenge": { "type": "string" },
+                "message": { "type": "string" },
+                "data": {},
+                "error": { "type": "string" },
+                "errors": { "$ref": "#/components/schemas/FieldErrors" },
+                "request_id": { "type": "string" },
+            },
+        })
+    }
+}
+
+/// Schema implementation for Problem
+impl Schema for Problem {
+    fn name() -> String {
+        String::from("Problem")
+    }
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["type", "title", "status"],
+            "properties": {
+                "type": { "type": "string", "format": "uri-reference" },
+                "title": { "type": "string" },
+                "status": { "type": "integer", "format": "int32" },
+                "detail": { "type": "string" },
+                "instance": { "type": "string" },
+                "errors": { "$ref": "#/components/schemas/FieldErrors" },
+                "challenge": { "type": "string" },
+                "request_id": { "type": "string" },
+            },
+            "additionalProperties": true,
+        })
+    }
+}
+
+/// Schema implementation for Token
+impl Schema for Token {
+    fn name() -> String {
+        String::from("Token")
+    }
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "access": string(),
+                "refresh": string(),
+                "web": string(),
+            },
+        })
+    }
+}
+
+/// Schema implementation for File
+impl Schema for File {
+    fn name() -> String {
+        String::from("File")
+    }
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "file_name": string(),
+                "file_size": string(),
+                "thumbnail": string(),
+                "height": number(),
+                "width": number(),
+                "mime_type": string(),
+                "label": string(),
+                "module": string(),
+            },
+        })
+    }
+}
+
+/// Schema implementation for UserAgent
+impl Schema for UserAgent {
+    fn name() -> String {
+        String::from("UserAgent")
+    }
+
+    fn schema() -> Value {
+        let version = json!({
+            "type": "object",
+            "properties": {
+                "name": string(),
+                "major": string(),
+                "minor": string(),
+                "patch": string(),
+                "patch_minor": string(),
+            },
+        });
+
+        let mut product = version.clone();
+        product["properties"].as_object_mut().map(|properties| properties.remove("patch_minor"));
+
+        json!({
+            "type": "object",
+            "properties": {
+                "ip": string(),
+                "product": product,
+                "os": version,
+                "device": {
+                    "type": "object",
+                    "properties": { "name": string(), "brand": string(), "model": string() },
+                },
+                "cpu": {
+                    "type": "object",
+                    "properties": { "architecture": string() },
+                },
+                "engine": version,
+            },
+        })
+    }
+}
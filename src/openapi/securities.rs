@@ -0,0 +1,84 @@
+use serde_json::{json, Value};
+
+use crate::Guard;
+
+/// Security enum, the guard mode of a route mapped to an OpenAPI security scheme
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Security {
+    /// No guard
+    #[default]
+    Public,
+    /// Bearer access token
+    Access,
+    /// Bearer access token, requests without token are allowed
+    OptionalAccess,
+    /// Bearer refresh token
+    Refresh,
+    /// Bearer web token
+    Web,
+}
+
+/// Convert guard to security
+///
+/// Example
+/// ```
+/// use library::Guard;
+/// use library::openapi::Security;
+///
+/// pub struct Actor {
+///     id: String,
+/// }
+///
+/// fn main() {
+///     let guard = Guard::<Actor>::refresh(vec![String::from("User")], None);
+///     assert_eq!(Security::from(&guard), Security::Refresh);
+/// }
+/// ```
+impl<T> From<&Guard<T>> for Security {
+    fn from(guard: &Guard<T>) -> Self {
+        match (guard.is_refresh_token, guard.is_web_token, guard.is_optional) {
+            (true, _, _) => Security::Refresh,
+            (_, true, _) => Security::Web,
+            (_, _, true) => Security::OptionalAccess,
+            _ => Security::Access,
+        }
+    }
+}
+
+/// Security implementation
+impl Security {
+    /// Retrieve security scheme name
+    pub fn scheme(&self) -> Option<&'static str> {
+        match self {
+            Security::Public => None,
+            Security::Access | Security::OptionalAccess => Some("accessToken"),
+            Security::Refresh => Some("refreshToken"),
+            Security::Web => Some("webToken"),
+        }
+    }
+
+    /// Retrieve operation security requirement
+    pub fn requirement(&self) -> Option<Value> {
+        let scheme = self.scheme()?;
+        match self {
+            Security::OptionalAccess => Some(json!([{ scheme: [] }, {}])),
+            _ => Some(json!([{ scheme: [] }]))
+        }
+    }
+
+    /// Retrieve security schemes of every guard mode, all sent as `Authorization: Bearer <token>`
+    pub fn schemes() -> Value {
+        let scheme = |description: &str| json!({
+            "type": "http",
+            "scheme": "bearer",
+            "bearerFormat": "PASETO",
+            "description": description,
+        });
+
+        json!({
+            "accessToken": scheme("Access token"),
+            "refreshToken": scheme("Refresh token"),
+            "webToken": scheme("Web token"),
+        })
+    }
+}
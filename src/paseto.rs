@@ -1,10 +1,17 @@
-use chrono::{DateTime, Duration, Utc};
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::error::InternalError;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::future::{err, ok, Ready};
 use paseto::tokens::{validate_local_token, PasetoBuilder, TimeBackend};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::Cipher;
 use crate::Errors;
+use crate::Payload;
 use crate::Token;
+use crate::locales;
+use crate::redactions::Redact;
 
 /// Struct container for paseto
 #[derive(Debug, Clone, PartialEq)]
@@ -18,6 +25,122 @@ pub struct Paseto {
     pub refresh_token_key_signing: Vec<u8>,
 }
 
+/// Struct container for authentication methods (`amr`) and time (`auth_time`) carried by tokens
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Authentication {
+    #[serde(default)]
+    pub amr: Vec<String>,
+    #[serde(default, with = "chrono::serde::ts_seconds_option")]
+    pub auth_time: Option<DateTime<Utc>>,
+}
+
+/// Authentication implementation
+impl Authentication {
+    /// Creates Authentication with the methods used just now, e.g. `pwd` and `otp`
+    ///
+    /// Example
+    /// ```
+    /// use library::paseto::Authentication;
+    ///
+    /// fn main() {
+    ///     let authentication = Authentication::new(vec!["pwd", "otp"]);
+    ///     assert!(authentication.has_mfa());
+    /// }
+    /// ```
+    pub fn new<T: Into<String>>(amr: Vec<T>) -> Self {
+        Self {
+            amr: amr.into_iter().map(|item| item.into()).collect(),
+            auth_time: Some(Utc::now()),
+        }
+    }
+
+    /// Check if a second factor was used, either `mfa` or at least two distinct methods
+    pub fn has_mfa(&self) -> bool {
+        let mut methods = self.amr.clone();
+        methods.sort();
+        methods.dedup();
+
+        methods.iter().any(|item| item == "mfa") || methods.len() > 1
+    }
+
+    /// Check if multi-factor authentication happened within the given age
+    ///
+    /// Example
+    /// ```
+    /// use chrono::Duration;
+    /// use library::paseto::Authentication;
+    ///
+    /// fn main() {
+    ///     let authentication = Authentication::new(vec!["pwd", "otp"]);
+    ///     assert!(authentication.is_recent_mfa(Duration::minutes(5)));
+    /// }
+    /// ```
+    pub fn is_recent_mfa(&self, max_age: Duration) -> bool {
+        match self.auth_time {
+            Some(auth_time) => self.has_mfa() && Utc::now().signed_duration_since(auth_time) <= max_age,
+            None => false
+        }
+    }
+}
+
+/// Struct container for impersonation, where an admin (`act` claim) acts as another user with restricted scopes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Impersonation {
+    pub actor: String,
+    pub scopes: Vec<String>,
+    pub lifetime: Duration,
+}
+
+/// Impersonation implementation
+impl Impersonation {
+    /// Creates Impersonation for the admin id with the allowed scopes and a 15 minute lifetime
+    ///
+    /// Example
+    /// ```
+    /// use chrono::Duration;
+    /// use library::paseto::Impersonation;
+    ///
+    /// fn main() {
+    ///     let mut impersonation = Impersonation::new("admin-12345", vec!["support"]);
+    ///     impersonation.set_lifetime(Duration::minutes(5));
+    /// }
+    /// ```
+    pub fn new<A: Into<String>, S: Into<String>>(actor: A, scopes: Vec<S>) -> Self {
+        Self {
+            actor: actor.into(),
+            scopes: scopes.into_iter().map(|item| item.into()).collect(),
+            lifetime: Duration::minutes(15),
+        }
+    }
+
+    /// Set token lifetime, capped to one hour
+    pub fn set_lifetime(&mut self, lifetime: Duration) -> &mut Self {
+        self.lifetime = lifetime.min(Duration::hours(1));
+        self
+    }
+
+    /// Check if impersonation was granted the scope
+    pub fn has_scope<T: AsRef<str>>(&self, scope: T) -> bool {
+        self.scopes.iter().any(|item| item == scope.as_ref())
+    }
+}
+
+/// Implement from request, use `Option<Impersonation>` to detect impersonated requests behind a `Guard`
+impl FromRequest for Impersonation {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut DevPayload) -> Self::Future {
+        match req.extensions().get::<Impersonation>() {
+            Some(impersonation) => ok(impersonation.clone()),
+            None => err(InternalError::from_response(
+                Errors::new(locales::message("not-impersonated")),
+                Payload::localized(400, "not-impersonated").to_response()
+            ).into())
+        }
+    }
+}
+
 /// Default implementation for Paseto
 impl Default for Paseto {
     fn default() -> Self {
@@ -33,6 +156,30 @@ impl Default for Paseto {
     }
 }
 
+/// Redact implementation for Paseto, empties the signing keys
+///
+/// Example
+/// ```
+/// use library::Paseto;
+/// use library::redactions::Redact;
+///
+/// fn main() {
+///     let mut paseto = Paseto::default();
+///     paseto.access_token_key_signing = b"YELLOW SUBMARINE, BLACK WIZARDRY".to_vec();
+///
+///     assert!(paseto.redacted().access_token_key_signing.is_empty());
+/// }
+/// ```
+impl Redact for Paseto {
+    fn redacted(&self) -> Self {
+        Self {
+            access_token_key_signing: Vec::new(),
+            refresh_token_key_signing: Vec::new(),
+            ..self.clone()
+        }
+    }
+}
+
 /// Paseto implementation
 impl Paseto {
     /// Implement new instance
@@ -181,7 +328,144 @@ impl Paseto {
         where I: Into<String>,
               C: Serialize
     {
-        let c = serde_json::to_value(claims.clone()).unwrap();
+        self.build_tokens(id, claims, None)
+    }
+
+    /// Generate access, refresh & web token pair carrying `amr` and `auth_time` claims for step-up guards
+    ///
+    /// Example
+    /// ```
+    /// use library::Paseto;
+    /// use library::paseto::Authentication;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Clone, Debug, Serialize)]
+    /// pub struct Actor {
+    ///     pub id: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     // Set paseto config
+    ///     let mut paseto = Paseto::with_app_name("Getaka Labs");
+    ///     paseto.access_token_key_unit = 15;
+    ///     paseto.access_token_key_time = String::from("Minutes");
+    ///     paseto.access_token_key_signing = String::from("BX8hllVNjp5IbB2NiUlt7OUctq71PKSq").into_bytes();
+    ///     paseto.refresh_token_key_unit = 30;
+    ///     paseto.refresh_token_key_time = String::from("Days");
+    ///     paseto.refresh_token_key_signing = String::from("-Xs6DCM7vQ9yKJX2uCQBgpqnWSyqDCGZ").into_bytes();
+    ///
+    ///     // Generate token after password and otp login
+    ///     let actor = Actor { id: String::from("id-12345") };
+    ///     let authentication = Authentication::new(vec!["pwd", "otp"]);
+    ///     let result = paseto.generate_authenticated_tokens(&actor.id, &actor, &authentication);
+    ///     if result.is_ok() {
+    ///         // Read authentication back from access token
+    ///         let access_token = result.unwrap().access.unwrap();
+    ///         let authentication = paseto.get_authentication(&access_token).unwrap();
+    ///         assert!(authentication.has_mfa());
+    ///     }
+    /// }
+    /// ```
+    pub fn generate_authenticated_tokens<I, C>(&self, id:I, claims: &C, authentication: &Authentication) -> Result<Token, Errors>
+        where I: Into<String>,
+              C: Serialize
+    {
+        self.build_tokens(id, claims, Some(authentication))
+    }
+
+    /// Generate a short lived access token for an admin acting as another user. Only the access token is set,
+    /// so impersonation cannot be extended with a refresh token
+    ///
+    /// Example
+    /// ```
+    /// use library::Paseto;
+    /// use library::paseto::Impersonation;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Clone, Debug, Serialize)]
+    /// pub struct Actor {
+    ///     pub id: String,
+    /// }
+    ///
+    /// fn main() {
+    ///     // Set paseto config
+    ///     let mut paseto = Paseto::with_app_name("Getaka Labs");
+    ///     paseto.access_token_key_signing = String::from("BX8hllVNjp5IbB2NiUlt7OUctq71PKSq").into_bytes();
+    ///
+    ///     // Log in as customer
+    ///     let customer = Actor { id: String::from("id-12345") };
+    ///     let impersonation = Impersonation::new("admin-12345", vec!["support"]);
+    ///     let token = paseto.generate_impersonation_tokens(&customer.id, &customer, &impersonation).unwrap();
+    ///
+    ///     // Read impersonation back from access token
+    ///     let result = paseto.get_impersonation(token.access.unwrap()).unwrap().unwrap();
+    ///     assert_eq!(result.actor, "admin-12345");
+    ///     assert_eq!(result.lifetime, impersonation.lifetime);
+    /// }
+    /// ```
+    pub fn generate_impersonation_tokens<I, C>(&self, id:I, claims: &C, impersonation: &Impersonation) -> Result<Token, Errors>
+        where I: Into<String>,
+              C: Serialize
+    {
+        // Set access token expiry, the issue time lets readers recover the lifetime
+        let now = Utc::now();
+        let expiry = now.checked_add_signed(impersonation.lifetime.min(Duration::hours(1))).unwrap();
+
+        // Set access token
+        let footer = format!("key-id:{}", &self.app_name);
+        let access_token = PasetoBuilder::new()
+            .set_encryption_key(&self.access_token_key_signing[..])
+            .set_issued_at(Some(now))
+            .set_expiration(&expiry)
+            .set_subject(&id.into())
+            .set_footer(footer.as_str())
+            .set_claim("data", serde_json::to_value(claims).unwrap())
+            .set_claim("act", serde_json::json!({ "sub": impersonation.actor }))
+            .set_claim("scope", serde_json::json!(impersonation.scopes.join(" ")))
+            .build();
+
+        if access_token.is_err() {
+            return Err(Errors::new("Unable to generate access token"));
+        }
+
+        // Create mutable token
+        let mut tokens = Token::new();
+        tokens.access = access_token.ok();
+
+        Ok(tokens)
+    }
+
+    /// Build paseto token with optional authentication claims
+    fn build_token(&self, key: &[u8], expiry: &DateTime<Utc>, subject: &str, data: serde_json::Value, authentication: Option<&Authentication>) -> Result<String, Errors> {
+        let footer = format!("key-id:{}", &self.app_name);
+        let result = match authentication {
+            Some(authentication) => PasetoBuilder::new()
+                .set_encryption_key(key)
+                .set_expiration(expiry)
+                .set_subject(subject)
+                .set_footer(footer.as_str())
+                .set_claim("data", data)
+                .set_claim("amr", serde_json::json!(authentication.amr))
+                .set_claim("auth_time", serde_json::json!(authentication.auth_time.map(|item| item.timestamp())))
+                .build(),
+            None => PasetoBuilder::new()
+                .set_encryption_key(key)
+                .set_expiration(expiry)
+                .set_subject(subject)
+                .set_footer(footer.as_str())
+                .set_claim("data", data)
+                .build()
+        };
+
+        result.map_err(|error| Errors::new(error.to_string()))
+    }
+
+    /// Generate token pair with optional authentication claims
+    fn build_tokens<I, C>(&self, id:I, claims: &C, authentication: Option<&Authentication>) -> Result<Token, Errors>
+        where I: Into<String>,
+              C: Serialize
+    {
+        let c = serde_json::to_value(claims).unwrap();
 
         // Set access token duration
         let access_token_duration = match self.access_token_key_time.as_ref() {
@@ -198,13 +482,7 @@ impl Paseto {
         let aid = id.into();
 
         // Set access token
-        let access_token = PasetoBuilder::new()
-            .set_encryption_key(&self.access_token_key_signing.clone()[..])
-            .set_expiration(&access_token_expiry)
-            .set_subject(&aid)
-            .set_footer(format!("key-id:{}", &self.app_name).as_str())
-            .set_claim("data", c.clone())
-            .build();
+        let access_token = self.build_token(&self.access_token_key_signing, &access_token_expiry, &aid, c.clone(), authentication);
 
         if access_token.is_err() {
             return Err(Errors::new("Unable to generate access token"));
@@ -222,13 +500,7 @@ impl Paseto {
         let refresh_token_expiry = Utc::now().checked_add_signed(refresh_token_duration).unwrap();
 
         // Set refresh token
-        let refresh_token = PasetoBuilder::new()
-            .set_encryption_key(&self.refresh_token_key_signing.clone()[..])
-            .set_expiration(&refresh_token_expiry)
-            .set_subject(&aid)
-            .set_footer(format!("key-id:{}", &self.app_name).as_str())
-            .set_claim("data", c.clone())
-            .build();
+        let refresh_token = self.build_token(&self.refresh_token_key_signing, &refresh_token_expiry, &aid, c.clone(), authentication);
 
         if refresh_token.is_err() {
             return Err(Errors::new("Unable to generate refresh token"));
@@ -237,7 +509,7 @@ impl Paseto {
         // Set cipher
         let cipher = Cipher::new();
         if cipher.is_err() {
-            return Err(Errors::new("Cipher library failed to initialize"));
+            return Err(Errors::config("Cipher library failed to initialize"));
         }
 
         // Shadow cipher
@@ -246,7 +518,7 @@ impl Paseto {
         // Create encrypted web token
         let encrypted = cipher.encrypt_web(c.to_string().trim());
         if encrypted.is_err() {
-            return Err(Errors::new("Encryption failed"));
+            return Err(Errors::config("Encryption failed"));
         }
 
         // Create mutable token
@@ -335,8 +607,8 @@ impl Paseto {
                 .as_str() == "this token is expired (exp claim).";
 
             return match is_expired {
-                true => Err(Errors::new("Your authentication token has expired")),
-                false => Err(Errors::new("Invalid authentication token"))
+                true => Err(Errors::expired(locales::message("expired-token"))),
+                false => Err(Errors::unauthorized(locales::message("invalid-authentication-token")))
             }
         }
 
@@ -344,13 +616,13 @@ impl Paseto {
         let result = result.unwrap();
         let result = result.get("data");
         if result.is_none() {
-            return Err(Errors::new("Invalid authentication token"));
+            return Err(Errors::unauthorized(locales::message("invalid-authentication-token")));
         }
 
         // Return value to custom struct
         let result:Result<C, _> = serde_json::from_value(result.unwrap().clone());
         if result.is_err() {
-            return Err(Errors::new("Invalid authentication token"));
+            return Err(Errors::unauthorized(locales::message("invalid-authentication-token")));
         }
 
         // Return claims
@@ -433,8 +705,8 @@ impl Paseto {
                 .as_str() == "this token is expired (exp claim).";
 
             return match is_expired {
-                true => Err(Errors::new("Your refresh token has expired")),
-                false => Err(Errors::new("Invalid refresh token"))
+                true => Err(Errors::expired(locales::message("expired-refresh-token"))),
+                false => Err(Errors::unauthorized(locales::message("invalid-refresh-token")))
             }
         }
 
@@ -442,13 +714,13 @@ impl Paseto {
         let result = result.unwrap();
         let result = result.get("data");
         if result.is_none() {
-            return Err(Errors::new("Invalid refresh token"));
+            return Err(Errors::unauthorized(locales::message("invalid-refresh-token")));
         }
 
         // Return value to custom struct
         let result:Result<C, _> = serde_json::from_value(result.unwrap().clone());
         if result.is_err() {
-            return Err(Errors::new("Invalid refresh token"));
+            return Err(Errors::unauthorized(locales::message("invalid-refresh-token")));
         }
 
         // Return claims
@@ -518,7 +790,7 @@ impl Paseto {
         // Set cipher
         let cipher = Cipher::new();
         if cipher.is_err() {
-            return Err(Errors::new("Cipher library failed to initialize"));
+            return Err(Errors::config("Cipher library failed to initialize"));
         }
 
         // Shadow cipher
@@ -527,19 +799,111 @@ impl Paseto {
         // Create decrypt web token
         let result = cipher.decrypt_web(token.into());
         if result.is_err() {
-            return Err(Errors::new("Decryption failed"));
+            return Err(Errors::unauthorized(locales::message("invalid-web-token")));
         }
 
         // Return value to custom struct
         let result:Result<C, _> = serde_json::from_str(&result.unwrap().clone());
         if result.is_err() {
-            return Err(Errors::new("Invalid authentication token"));
+            return Err(Errors::unauthorized(locales::message("invalid-web-token")));
         }
 
         // Return claims
         Ok(result.unwrap())
     }
 
+    /// Retrieve `amr` and `auth_time` claims from access token. Tokens without them return empty Authentication
+    ///
+    /// Example
+    /// ```
+    /// use library::Paseto;
+    ///
+    /// fn main() {
+    ///     let mut paseto = Paseto::with_app_name("Getaka Labs");
+    ///     paseto.access_token_key_signing = String::from("BX8hllVNjp5IbB2NiUlt7OUctq71PKSq").into_bytes();
+    ///
+    ///     let result = paseto.get_authentication("v2.local.invalid");
+    ///     assert!(result.is_err());
+    /// }
+    /// ```
+    pub fn get_authentication<T: Into<String>>(&self, token: T) -> Result<Authentication, Errors> {
+        self.read_authentication(token.into(), &self.access_token_key_signing)
+    }
+
+    /// Retrieve `amr` and `auth_time` claims from refresh token, e.g. to carry them over when refreshing tokens
+    pub fn get_refresh_authentication<T: Into<String>>(&self, token: T) -> Result<Authentication, Errors> {
+        self.read_authentication(token.into(), &self.refresh_token_key_signing)
+    }
+
+    /// Read authentication claims from token signed with key
+    fn read_authentication(&self, token: String, key: &[u8]) -> Result<Authentication, Errors> {
+        // Verify token
+        let result = validate_local_token(
+            &token,
+            Some(format!("key-id:{}", &self.app_name).as_str()),
+            key,
+            &TimeBackend::Chrono
+        );
+
+        // Check if result is error
+        let result = match result {
+            Ok(result) => result,
+            Err(_) => return Err(Errors::unauthorized(locales::message("invalid-authentication-token")))
+        };
+
+        // Retrieve authentication methods
+        let amr = result.get("amr")
+            .and_then(|item| serde_json::from_value::<Vec<String>>(item.clone()).ok())
+            .unwrap_or_default();
+
+        // Retrieve authentication time
+        let auth_time = result.get("auth_time")
+            .and_then(|item| item.as_i64())
+            .and_then(|item| Utc.timestamp_opt(item, 0).single());
+
+        Ok(Authentication { amr, auth_time })
+    }
+
+    /// Retrieve `act` and `scope` claims from access token, with the lifetime the token was issued for (`exp` minus `iat`).
+    /// `None` when the token is not an impersonation token, and an error when its impersonation claims are malformed
+    pub fn get_impersonation<T: Into<String>>(&self, token: T) -> Result<Option<Impersonation>, Errors> {
+        // Verify token
+        let result = validate_local_token(
+            &token.into(),
+            Some(format!("key-id:{}", &self.app_name).as_str()),
+            &self.access_token_key_signing.clone()[..],
+            &TimeBackend::Chrono
+        );
+
+        // Check if result is error
+        let result = match result {
+            Ok(result) => result,
+            Err(_) => return Err(Errors::unauthorized(locales::message("invalid-authentication-token")))
+        };
+
+        // Retrieve actor
+        let actor = match result.get("act") {
+            Some(act) => act.get("sub").and_then(|item| item.as_str()).ok_or_else(|| Errors::unauthorized(locales::message("invalid-impersonation")))?,
+            None => return Ok(None)
+        };
+
+        // Retrieve scopes
+        let scopes = match result.get("scope") {
+            Some(scope) => scope.as_str().ok_or_else(|| Errors::unauthorized(locales::message("invalid-impersonation")))?.split_whitespace().collect::<Vec<&str>>(),
+            None => Vec::new()
+        };
+
+        // Retrieve lifetime from the issue and expiry time
+        let time = |claim: &str| result.get(claim)
+            .and_then(|item| serde_json::from_value::<DateTime<Utc>>(item.clone()).ok())
+            .ok_or_else(|| Errors::unauthorized(locales::message("invalid-impersonation")));
+
+        let mut impersonation = Impersonation::new(actor, scopes);
+        impersonation.lifetime = time("exp")? - time("iat")?;
+
+        Ok(Some(impersonation))
+    }
+
     /// Retrieve access token expiry
     ///
     /// Example
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::Errors;
+use crate::locales::Catalog;
+
+/// Struct container for a failed password rule, `rule` is the message key in the locale catalogs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Failure {
+    pub rule: String,
+    pub args: BTreeMap<String, String>,
+}
+
+/// Failure implementation
+impl Failure {
+    /// Create failure for the rule
+    pub fn new<T: Into<String>>(rule: T) -> Self {
+        Self { rule: rule.into(), args: BTreeMap::new() }
+    }
+
+    /// Set message argument
+    pub fn set_arg<K: Into<String>, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.args.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Translate failure message with the catalog
+    pub fn message(&self, catalog: &Catalog, locale: &str) -> String {
+        let args: Vec<(&str, &str)> = self.args.iter().map(|(key, value)| (key.as_str(), value.as_str())).collect();
+        catalog.translate(locale, &self.rule, &args)
+    }
+}
+
+/// Struct container for the result of evaluating a password against a `Policy`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Evaluation {
+    pub score: u8,
+    pub entropy: f64,
+    pub failures: Vec<Failure>,
+}
+
+/// Evaluation implementation
+impl Evaluation {
+    /// Check if the password passed every rule
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Retrieve failed rule keys
+    pub fn rules(&self) -> Vec<&str> {
+        self.failures.iter().map(|failure| failure.rule.as_str()).collect()
+    }
+
+    /// Translate failure messages with the catalog
+    pub fn messages(&self, catalog: &Catalog, locale: &str) -> Vec<String> {
+        self.failures.iter().map(|failure| failure.message(catalog, locale)).collect()
+    }
+
+    /// Convert failures into a validation error for the field, rendered in `Payload.errors`
+    ///
+    /// Example
+    /// ```
+    /// use library::passwords::Policy;
+    ///
+    /// fn main() {
+    ///     let evaluation = Policy::new().evaluate("abc", &[]);
+    ///     let error = evaluation.to_errors("password").unwrap_err();
+    ///
+    ///     assert_eq!(error.fields().unwrap()["password"][0], "Password must be at least 8 characters");
+    /// }
+    /// ```
+    pub fn to_errors(&self, field: &str) -> Result<(), Errors> {
+        self.to_localized_errors(field, crate::locales::builtin(), "en")
+    }
+
+    /// Convert failures into a validation error for the field with messages translated for the locale
+    pub fn to_localized_errors(&self, field: &str, catalog: &Catalog, locale: &str) -> Result<(), Errors> {
+        match self.is_valid() {
+            true => Ok(()),
+            false => Err(Errors::validation(
+                catalog.translate(locale, "validation-failed", &[]),
+                self.messages(catalog, locale).into_iter().map(|message| (field, message)).collect()
+            ))
+        }
+    }
+}
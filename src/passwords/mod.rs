@@ -0,0 +1,59 @@
+pub mod evaluations;
+pub mod policies;
+
+use actix_web::HttpRequest;
+use actix_web::error::InternalError;
+use actix_web::web::Data;
+
+pub use crate::passwords::evaluations::{Evaluation, Failure};
+pub use crate::passwords::policies::Policy;
+
+use crate::Payload;
+use crate::locales;
+use crate::problems;
+
+/// Evaluate password with the registered `Policy` (or the default rules). Failures respond 422 with translated messages for the field
+///
+/// Example
+/// ```
+/// use actix_web::{web, App, HttpRequest, HttpResponse};
+/// use library::passwords::{self, Policy};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// pub struct Signup {
+///     pub email: String,
+///     pub password: String,
+/// }
+///
+/// async fn signup(req: HttpRequest, form: web::Json<Signup>) -> actix_web::Result<HttpResponse> {
+///     let evaluation = passwords::validate(&req, "password", &form.password, &[&form.email])?;
+///     Ok(HttpResponse::Ok().json(evaluation.score))
+/// }
+///
+/// fn main() {
+///     let mut policy = Policy::new();
+///     policy.set_min_score(3).add_banned(vec!["acme2024"]);
+///
+///     App::new()
+///         .app_data(web::Data::new(policy))
+///         .route("/signup", web::post().to(signup));
+/// }
+/// ```
+pub fn validate(req: &HttpRequest, field: &str, password: &str, user_inputs: &[&str]) -> Result<Evaluation, actix_web::Error> {
+    let evaluation = match req.app_data::<Data<Policy>>() {
+        Some(policy) => policy.evaluate(password, user_inputs),
+        None => Policy::default().evaluate(password, user_inputs)
+    };
+
+    let error = match evaluation.to_localized_errors(field, locales::catalog(req), &locales::negotiate(req)) {
+        Ok(_) => return Ok(evaluation),
+        Err(error) => error
+    };
+
+    let mut payload = Payload::new(422);
+    payload.error = error.as_str().to_string();
+    payload.errors = serde_json::to_value(error.fields()).unwrap_or_default();
+
+    Err(InternalError::from_response(error, problems::respond(req, &payload)).into())
+}
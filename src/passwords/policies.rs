@@ -0,0 +1,257 @@
+use std::collections::HashSet;
+
+use crate::passwords::{Evaluation, Failure};
+
+/// Built-in list of commonly used passwords
+const BANNED: &str = include_str!("banned.txt");
+
+/// Struct container for password rules, register it with `app_data` to configure `passwords::validate`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Policy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_number: bool,
+    pub require_symbol: bool,
+    pub min_score: u8,
+    pub banned: HashSet<String>,
+    pub check_user_inputs: bool,
+}
+
+/// Default implementation for Policy, 8 to 128 characters scoring at least 2 without common passwords or user info
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_number: false,
+            require_symbol: false,
+            min_score: 2,
+            banned: BANNED.lines().map(|item| item.trim().to_lowercase()).filter(|item| !item.is_empty()).collect(),
+            check_user_inputs: true,
+        }
+    }
+}
+
+/// Policy implementation
+impl Policy {
+    /// Create policy with the default rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set minimum and maximum number of characters
+    pub fn set_length(&mut self, min_length: usize, max_length: usize) -> &mut Self {
+        self.min_length = min_length;
+        self.max_length = max_length;
+        self
+    }
+
+    /// Set required character classes, letters are checked with unicode case so `é` counts as lowercase
+    pub fn set_required(&mut self, lowercase: bool, uppercase: bool, number: bool, symbol: bool) -> &mut Self {
+        self.require_lowercase = lowercase;
+        self.require_uppercase = uppercase;
+        self.require_number = number;
+        self.require_symbol = symbol;
+        self
+    }
+
+    /// Set minimum strength score from 0 (too guessable) to 4 (very unguessable)
+    pub fn set_min_score(&mut self, min_score: u8) -> &mut Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Add passwords to the banned list
+    pub fn add_banned<T: Into<String>>(&mut self, passwords: Vec<T>) -> &mut Self {
+        self.banned.extend(passwords.into_iter().map(|item| item.into().to_lowercase()));
+        self
+    }
+
+    /// Set if passwords containing user info (name, email, username) are rejected
+    pub fn set_check_user_inputs(&mut self, check_user_inputs: bool) -> &mut Self {
+        self.check_user_inputs = check_user_inputs;
+        self
+    }
+
+    /// Evaluate password against the rules, `user_inputs` are values like the name or email of the user
+    ///
+    /// Example
+    /// ```
+    /// use library::passwords::Policy;
+    ///
+    /// fn main() {
+    ///     let mut policy = Policy::new();
+    ///     policy.set_required(true, true, true, false);
+    ///
+    ///     let evaluation = policy.evaluate("johndoe2024", &["john.doe@example.com"]);
+    ///     assert_eq!(evaluation.rules(), vec!["password-uppercase", "password-user-input"]);
+    ///
+    ///     let evaluation = policy.evaluate("Correct-Horse-Battery-9", &["john.doe@example.com"]);
+    ///     assert!(evaluation.is_valid());
+    ///     assert!(evaluation.score >= 3);
+    ///
+    ///     let evaluation = Policy::new().evaluate("Welcome-Horse-Battery-92", &["jane@gmail.com"]);
+    ///     assert!(evaluation.is_valid());
+    /// }
+    /// ```
+    pub fn evaluate(&self, password: &str, user_inputs: &[&str]) -> Evaluation {
+        let length = password.chars().count();
+        let mut failures = Vec::new();
+
+        if length < self.min_length {
+            failures.push(Failure::new("password-min-length").set_arg("min", self.min_length));
+        }
+
+        if length > self.max_length {
+            failures.push(Failure::new("password-max-length").set_arg("max", self.max_length));
+        }
+
+        // Check character classes
+        let classes = [
+            (self.require_lowercase, "password-lowercase", password.chars().any(char::is_lowercase)),
+            (self.require_uppercase, "password-uppercase", password.chars().any(char::is_uppercase)),
+            (self.require_number, "password-number", password.chars().any(char::is_numeric)),
+            (self.require_symbol, "password-symbol", password.chars().any(is_symbol)),
+        ];
+
+        for (is_required, rule, is_present) in classes {
+            if is_required && !is_present {
+                failures.push(Failure::new(rule));
+            }
+        }
+
+        // Check guessable passwords
+        let is_banned = self.is_banned(password);
+        if is_banned {
+            failures.push(Failure::new("password-banned"));
+        }
+
+        let matched_inputs = match self.check_user_inputs {
+            true => matched_inputs(password, user_inputs),
+            false => Vec::new()
+        };
+
+        if !matched_inputs.is_empty() {
+            failures.push(Failure::new("password-user-input"));
+        }
+
+        let entropy = entropy(password, is_banned, &matched_inputs);
+        let score = score(entropy);
+        if score < self.min_score {
+            failures.push(Failure::new("password-weak").set_arg("score", score));
+        }
+
+        Evaluation { score, entropy, failures }
+    }
+
+    /// Check if password is banned, including leetspeak variants and trailing digits or symbols, e.g. `P@ssw0rd!1`
+    pub fn is_banned(&self, password: &str) -> bool {
+        let password = password.to_lowercase();
+        let trimmed = password.trim_end_matches(|c: char| !c.is_alphabetic());
+
+        [password.as_str(), trimmed].iter()
+            .flat_map(|item| [item.to_string(), unleet(item)])
+            .any(|item| {
+                let trimmed = item.trim_end_matches(|c: char| !c.is_alphabetic());
+                self.banned.contains(&item) || (!trimmed.is_empty() && self.banned.contains(trimmed))
+            })
+    }
+}
+
+// Check if character is a symbol, e.g. punctuation or whitespace
+fn is_symbol(c: char) -> bool {
+    !c.is_alphanumeric()
+}
+
+// Replace common leetspeak substitutions with letters
+fn unleet(value: &str) -> String {
+    value.chars()
+        .map(|c| match c {
+            '@' | '4' => 'a',
+            '3' => 'e',
+            '1' | '!' => 'i',
+            '0' => 'o',
+            '$' | '5' => 's',
+            '7' => 't',
+            _ => c
+        })
+        .collect()
+}
+
+// Retrieve user inputs (or their parts of at least 4 characters, e.g. `john` of `john.doe`) found in the password.
+// Only the name of an email is checked so common domains and tlds (`gmail`, `com`) never match
+fn matched_inputs(password: &str, user_inputs: &[&str]) -> Vec<String> {
+    let password = password.to_lowercase();
+
+    user_inputs.iter()
+        .flat_map(|input| {
+            let input = input.to_lowercase();
+            let name = match input.split_once('@') {
+                Some((name, _)) => name.to_string(),
+                None => input
+            };
+
+            let mut parts: Vec<String> = name.split(|c: char| !c.is_alphanumeric())
+                .filter(|part| part.chars().count() >= 4)
+                .map(String::from)
+                .collect();
+
+            if name.chars().count() >= 3 && !parts.contains(&name) {
+                parts.push(name);
+            }
+
+            parts
+        })
+        .filter(|part| password.contains(part.as_str()))
+        .collect()
+}
+
+// Estimate entropy in bits from the character pool, discounting repeats, sequences and guessable parts
+fn entropy(password: &str, is_banned: bool, matched_inputs: &[String]) -> f64 {
+    let chars: Vec<char> = password.chars().collect();
+    if chars.is_empty() || is_banned {
+        return 0.0;
+    }
+
+    let pools = [
+        (chars.iter().any(|c| c.is_ascii_lowercase()), 26.0),
+        (chars.iter().any(|c| c.is_ascii_uppercase()), 26.0),
+        (chars.iter().any(|c| c.is_ascii_digit()), 10.0),
+        (chars.iter().any(|c| c.is_ascii() && !c.is_ascii_alphanumeric()), 33.0),
+        (chars.iter().any(|c| !c.is_ascii()), 100.0),
+    ];
+    let pool: f64 = pools.iter().filter(|(is_present, _)| *is_present).map(|(_, size)| size).sum();
+
+    // Repeated and sequential characters (aaa, abc, 321) add little
+    let mut length = 1.0;
+    for index in 1..chars.len() {
+        let step = chars[index] as i64 - chars[index - 1] as i64;
+        length += match step.abs() <= 1 {
+            true => 0.25,
+            false => 1.0
+        };
+    }
+
+    // User inputs are guessable, count each as a single character
+    let longest = matched_inputs.iter().map(|item| item.chars().count()).max().unwrap_or_default();
+    if longest > 0 {
+        length = (length - longest as f64 + 1.0).max(1.0);
+    }
+
+    length * pool.log2()
+}
+
+// Convert entropy into a 0 to 4 score
+fn score(entropy: f64) -> u8 {
+    match entropy {
+        _ if entropy < 28.0 => 0,
+        _ if entropy < 36.0 => 1,
+        _ if entropy < 60.0 => 2,
+        _ if entropy < 80.0 => 3,
+        _ => 4
+    }
+}
@@ -0,0 +1,59 @@
+use crate::patches::Patch;
+
+/// Struct container for the fields a patch is allowed to change, register it with `app_data` on the resource
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Allowlist {
+    pub paths: Vec<String>,
+}
+
+/// Allowlist implementation
+impl Allowlist {
+    /// Create allowlist from field paths (`address.city`, `items`) or json pointers (`/address/city`)
+    ///
+    /// Example
+    /// ```
+    /// use library::patches::Allowlist;
+    ///
+    /// fn main() {
+    ///     let allowlist = Allowlist::new(vec!["name", "address.city", "/tags"]);
+    ///
+    ///     assert!(allowlist.is_allowed("/address/city"));
+    ///     assert!(allowlist.is_allowed("/tags/0"));
+    ///     assert!(!allowlist.is_allowed("/address"));
+    ///     assert!(!allowlist.is_allowed("/role"));
+    /// }
+    /// ```
+    pub fn new<T: Into<String>>(paths: Vec<T>) -> Self {
+        let mut allowlist = Self::default();
+        for path in paths {
+            allowlist.add(path);
+        }
+
+        allowlist
+    }
+
+    /// Add field path or json pointer to the allowlist
+    pub fn add<T: Into<String>>(&mut self, path: T) -> &mut Self {
+        let path = path.into();
+        let path = match path.starts_with('/') {
+            true => path,
+            false => format!("/{}", path.replace('~', "~0").replace('/', "~1").replace('.', "/"))
+        };
+
+        self.paths.push(path);
+        self
+    }
+
+    /// Check if json pointer is an allowed path or inside one
+    pub fn is_allowed(&self, pointer: &str) -> bool {
+        self.paths.iter().any(|path| pointer == path || pointer.starts_with(&format!("{}/", path)))
+    }
+
+    /// Retrieve json pointers changed by the patch that are not allowed
+    pub fn denied(&self, patch: &Patch) -> Vec<String> {
+        patch.changed_paths()
+            .into_iter()
+            .filter(|path| !self.is_allowed(path))
+            .collect()
+    }
+}
@@ -0,0 +1,71 @@
+pub mod allowlists;
+pub mod operations;
+pub mod patches;
+
+use serde_json::{Map, Value};
+
+pub use crate::patches::allowlists::Allowlist;
+pub use crate::patches::operations::Operation;
+pub use crate::patches::patches::Patch;
+
+/// Merge patch into target using RFC 7396 JSON Merge Patch, `null` members are removed
+///
+/// Example
+/// ```
+/// use library::patches;
+/// use serde_json::json;
+///
+/// fn main() {
+///     let mut value = json!({ "name": "John", "address": { "city": "Manila", "zip": "1000" } });
+///     patches::merge(&mut value, &json!({ "address": { "zip": null }, "age": 30 }));
+///
+///     assert_eq!(value, json!({ "name": "John", "address": { "city": "Manila" }, "age": 30 }));
+/// }
+/// ```
+pub fn merge(target: &mut Value, patch: &Value) {
+    let patch = match patch {
+        Value::Object(patch) => patch,
+        _ => {
+            *target = patch.clone();
+            return;
+        }
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    if let Value::Object(map) = target {
+        for (key, value) in patch {
+            match value {
+                Value::Null => map.retain(|item, _| item != key),
+                _ => merge(map.entry(key.clone()).or_insert(Value::Null), value)
+            }
+        }
+    }
+}
+
+/// Convert json pointer into field path used by `Payload.errors`
+///
+/// Example
+/// ```
+/// use library::patches;
+///
+/// fn main() {
+///     assert_eq!(patches::field("/items/2/price"), "items[2].price");
+///     assert_eq!(patches::field("/tags/-"), "tags[-]");
+/// }
+/// ```
+pub fn field(pointer: &str) -> String {
+    let mut field = String::new();
+    for token in pointer.split('/').skip(1) {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        match token == "-" || (!token.is_empty() && token.chars().all(|c| c.is_ascii_digit())) {
+            true => field.push_str(&format!("[{}]", token)),
+            false if field.is_empty() => field.push_str(&token),
+            false => field.push_str(&format!(".{}", token))
+        }
+    }
+
+    field
+}
@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Errors;
+use crate::patches;
+
+/// Struct container for RFC 6902 JSON Patch operations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum Operation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+/// Operation implementation
+impl Operation {
+    /// Retrieve target path of the operation
+    pub fn path(&self) -> &str {
+        match self {
+            Operation::Add { path, .. } |
+            Operation::Remove { path } |
+            Operation::Replace { path, .. } |
+            Operation::Move { path, .. } |
+            Operation::Copy { path, .. } |
+            Operation::Test { path, .. } => path
+        }
+    }
+
+    /// Retrieve paths changed by the operation, `move` changes its source too while `copy` and `test` only read theirs
+    pub fn changed_paths(&self) -> Vec<&str> {
+        match self {
+            Operation::Move { from, path } => vec![from, path],
+            Operation::Test { .. } => vec![],
+            _ => vec![self.path()]
+        }
+    }
+
+    /// Apply operation on value, errors are reported per field path
+    ///
+    /// Example
+    /// ```
+    /// use library::patches::Operation;
+    /// use serde_json::json;
+    ///
+    /// fn main() {
+    ///     let mut value = json!({ "items": [{ "price": 10 }] });
+    ///
+    ///     Operation::Replace { path: "/items/0/price".to_string(), value: json!(12) }.apply(&mut value).unwrap();
+    ///     assert_eq!(value, json!({ "items": [{ "price": 12 }] }));
+    ///
+    ///     let error = Operation::Remove { path: "/items/2/price".to_string() }.apply(&mut value).unwrap_err();
+    ///     assert_eq!(error.fields().unwrap()["items[2].price"], vec!["Path does not exist"]);
+    /// }
+    /// ```
+    pub fn apply(&self, target: &mut Value) -> Result<(), Errors> {
+        match self {
+            Operation::Add { path, value } => add(target, path, value.clone()),
+            Operation::Remove { path } => remove(target, path).map(|_| ()),
+            Operation::Replace { path, value } => {
+                let item = pointer_mut(target, path)?;
+                *item = value.clone();
+                Ok(())
+            },
+            Operation::Move { from, path } => {
+                if path.starts_with(&format!("{}/", from)) {
+                    return Err(error(path, "Cannot move a value into one of its children"));
+                }
+
+                let value = remove(target, from)?;
+                add(target, path, value)
+            },
+            Operation::Copy { from, path } => {
+                let value = pointer(target, from)?.clone();
+                add(target, path, value)
+            },
+            Operation::Test { path, value } => match pointer(target, path)? == value {
+                true => Ok(()),
+                false => Err(error(path, "Test operation failed"))
+            }
+        }
+    }
+}
+
+// Create patch error for the field of the path
+fn error(path: &str, message: &str) -> Errors {
+    Errors::validation("Unable to apply patch", vec![(patches::field(path), message)])
+}
+
+// Split path into unescaped reference tokens
+fn tokens(path: &str) -> Result<Vec<String>, Errors> {
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    match path.strip_prefix('/') {
+        Some(path) => Ok(path.split('/').map(|item| item.replace("~1", "/").replace("~0", "~")).collect()),
+        None => Err(error(path, "Invalid path"))
+    }
+}
+
+// Parse array index token, `-` is only allowed when appending
+fn index(path: &str, token: &str, len: usize, is_append: bool) -> Result<usize, Errors> {
+    if is_append && token == "-" {
+        return Ok(len);
+    }
+
+    match token.parse::<usize>() {
+        Ok(index) if index < len || (is_append && index == len) => Ok(index),
+        Ok(_) => Err(error(path, "Array index is out of bounds")),
+        Err(_) => Err(error(path, "Invalid array index"))
+    }
+}
+
+// Retrieve value of the path
+fn pointer<'a>(target: &'a Value, path: &str) -> Result<&'a Value, Errors> {
+    tokens(path)?;
+    target.pointer(path).ok_or_else(|| error(path, "Path does not exist"))
+}
+
+// Retrieve mutable value of the path
+fn pointer_mut<'a>(target: &'a mut Value, path: &str) -> Result<&'a mut Value, Errors> {
+    tokens(path)?;
+    target.pointer_mut(path).ok_or_else(|| error(path, "Path does not exist"))
+}
+
+// Split path into parent value and last token
+fn parent<'a>(target: &'a mut Value, path: &str) -> Result<(&'a mut Value, String), Errors> {
+    let last = tokens(path)?.pop().unwrap_or_default();
+    let parent = &path[..path.rfind('/').unwrap_or_default()];
+
+    match target.pointer_mut(parent) {
+        Some(parent) => Ok((parent, last)),
+        None => Err(error(path, "Path does not exist"))
+    }
+}
+
+// Add value to the path, replacing the whole document on empty path
+fn add(target: &mut Value, path: &str, value: Value) -> Result<(), Errors> {
+    if path.is_empty() {
+        *target = value;
+        return Ok(());
+    }
+
+    let (parent, token) = parent(target, path)?;
+    match parent {
+        Value::Object(map) => {
+            map.insert(token, value);
+            Ok(())
+        },
+        Value::Array(items) => {
+            let index = index(path, &token, items.len(), true)?;
+            items.insert(index, value);
+            Ok(())
+        },
+        _ => Err(error(path, "Path does not exist"))
+    }
+}
+
+// Remove value of the path
+fn remove(target: &mut Value, path: &str) -> Result<Value, Errors> {
+    if path.is_empty() {
+        return Ok(std::mem::take(target));
+    }
+
+    let (parent, token) = parent(target, path)?;
+    match parent {
+        Value::Object(map) => {
+            // Retain other members so key order is kept
+            let value = map.get(&token).cloned().ok_or_else(|| error(path, "Path does not exist"))?;
+            map.retain(|key, _| key != &token);
+            Ok(value)
+        },
+        Value::Array(items) => {
+            let index = index(path, &token, items.len(), false)?;
+            Ok(items.remove(index))
+        },
+        _ => Err(error(path, "Path does not exist"))
+    }
+}
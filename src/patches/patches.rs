@@ -0,0 +1,200 @@
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::error::InternalError;
+use actix_web::web::{Bytes, Data};
+use futures::future::LocalBoxFuture;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{Errors, Payload};
+use crate::locales;
+use crate::patches::{self, Allowlist, Operation};
+use crate::problems;
+
+/// Content type of RFC 7396 JSON Merge Patch bodies
+pub const MERGE_PATCH: &str = "application/merge-patch+json";
+
+/// Content type of RFC 6902 JSON Patch bodies
+pub const JSON_PATCH: &str = "application/json-patch+json";
+
+/// Struct container for partial updates. As an extractor it reads `application/merge-patch+json` (or `application/json`)
+/// and `application/json-patch+json` bodies, rejecting paths outside the `Allowlist` app data when registered
+///
+/// Example
+/// ```
+/// use actix_web::{web, App, HttpResponse};
+/// use library::Errors;
+/// use library::patches::{Allowlist, Patch};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// pub struct Profile {
+///     pub name: String,
+///     pub bio: Option<String>,
+/// }
+///
+/// async fn update(patch: Patch) -> Result<HttpResponse, Errors> {
+///     let profile = Profile { name: String::from("John"), bio: None };
+///     let profile = patch.apply_to(&profile)?;
+///
+///     Ok(HttpResponse::Ok().json(profile))
+/// }
+///
+/// fn main() {
+///     App::new().service(
+///         web::resource("/profile")
+///             .app_data(web::Data::new(Allowlist::new(vec!["name", "bio"])))
+///             .route(web::patch().to(update))
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Patch {
+    Merge(Value),
+    Json(Vec<Operation>),
+}
+
+/// Patch implementation
+impl Patch {
+    /// Apply patch on value. JSON Patch operations are atomic, the value is left untouched when one fails
+    ///
+    /// Example
+    /// ```
+    /// use library::patches::Patch;
+    /// use serde_json::json;
+    ///
+    /// fn main() {
+    ///     let mut value = json!({ "tags": ["a"] });
+    ///     let patch: Patch = Patch::Json(serde_json::from_value(json!([
+    ///         { "op": "add", "path": "/tags/-", "value": "b" },
+    ///         { "op": "test", "path": "/tags/0", "value": "z" }
+    ///     ])).unwrap());
+    ///
+    ///     let error = patch.apply(&mut value).unwrap_err();
+    ///     assert_eq!(error.fields().unwrap()["tags[0]"], vec!["Test operation failed"]);
+    ///     assert_eq!(value, json!({ "tags": ["a"] }));
+    /// }
+    /// ```
+    pub fn apply(&self, target: &mut Value) -> Result<(), Errors> {
+        match self {
+            Patch::Merge(patch) => patches::merge(target, patch),
+            Patch::Json(operations) => {
+                let mut value = target.clone();
+                for operation in operations {
+                    operation.apply(&mut value)?;
+                }
+
+                *target = value;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply patch on a typed struct, returning the patched copy
+    pub fn apply_to<T: Serialize + DeserializeOwned>(&self, item: &T) -> Result<T, Errors> {
+        let mut value = serde_json::to_value(item).map_err(|error| Errors::new(error.to_string()))?;
+        self.apply(&mut value)?;
+
+        serde_json::from_value(value).map_err(|error| Errors::validation("Unable to apply patch", vec![("", error.to_string())]))
+    }
+
+    /// Retrieve json pointers changed by the patch, merge patches report each replaced or removed leaf
+    ///
+    /// Example
+    /// ```
+    /// use library::patches::Patch;
+    /// use serde_json::json;
+    ///
+    /// fn main() {
+    ///     let patch = Patch::Merge(json!({ "name": "Jane", "address": { "city": null } }));
+    ///     assert_eq!(patch.changed_paths(), vec!["/name", "/address/city"]);
+    /// }
+    /// ```
+    pub fn changed_paths(&self) -> Vec<String> {
+        match self {
+            Patch::Merge(patch) => {
+                let mut paths = Vec::new();
+                leaves(&mut paths, "", patch);
+                paths
+            },
+            Patch::Json(operations) => operations.iter()
+                .flat_map(|operation| operation.changed_paths())
+                .map(String::from)
+                .collect()
+        }
+    }
+}
+
+// Collect json pointers of merge patch leaves
+fn leaves(paths: &mut Vec<String>, prefix: &str, patch: &Value) {
+    match patch {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                leaves(paths, &format!("{}/{}", prefix, key.replace('~', "~0").replace('/', "~1")), value);
+            }
+        },
+        _ => paths.push(prefix.to_string())
+    }
+}
+
+/// FromRequest implementation for Patch
+impl FromRequest for Patch {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut DevPayload) -> Self::Future {
+        let req = req.clone();
+        let bytes = Bytes::from_request(&req, payload);
+
+        Box::pin(async move {
+            let content_type = req.content_type().to_lowercase();
+            if ![MERGE_PATCH, JSON_PATCH, "application/json"].contains(&content_type.as_str()) {
+                let mut response = Payload::new(415);
+                response.error = locales::translate(&req, "patch-content-type", &[]);
+
+                return Err(InternalError::from_response(Errors::new(response.error.clone()), problems::respond(&req, &response)).into());
+            }
+
+            // Parse body by content type
+            let bytes = bytes.await?;
+            let patch = match content_type.as_str() {
+                JSON_PATCH => serde_json::from_slice(&bytes).map(Patch::Json),
+                _ => serde_json::from_slice(&bytes).map(Patch::Merge),
+            };
+
+            let patch = match patch {
+                Ok(patch) => patch,
+                Err(error) => {
+                    let mut response = Payload::new(400);
+                    response.error = locales::translate(&req, "json-deserialize", &[("error", &error.to_string())]);
+
+                    return Err(InternalError::from_response(error, problems::respond(&req, &response)).into());
+                }
+            };
+
+            // Check changed paths against allowlist
+            let denied = match req.app_data::<Data<Allowlist>>() {
+                Some(allowlist) => allowlist.denied(&patch),
+                None => Vec::new()
+            };
+
+            if denied.is_empty() {
+                return Ok(patch);
+            }
+
+            let message = locales::translate(&req, "patch-denied", &[]);
+            let error = Errors::validation(
+                locales::translate(&req, "validation-failed", &[]),
+                denied.iter().map(|path| (patches::field(path), message.clone())).collect()
+            );
+
+            let mut response = Payload::new(422);
+            response.error = error.as_str().to_string();
+            response.errors = serde_json::to_value(error.fields()).unwrap_or_default();
+
+            Err(InternalError::from_response(error, problems::respond(&req, &response)).into())
+        })
+    }
+}
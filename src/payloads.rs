@@ -1,8 +1,17 @@
 use actix_web::{HttpRequest, HttpResponse, Responder};
 use std::fmt::Display;
 use actix_web::body::BoxBody;
+use actix_web::http::StatusCode;
 use serde::Serialize;
 
+use crate::Errors;
+use crate::locales;
+use crate::problems;
+
+/// Message key of a built-in payload error, kept in the response extensions so `problems::convert` can still translate it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageKey(pub String);
+
 /// Struct container for payloads options
 #[derive(Debug, Clone, Serialize)]
 pub struct Payload {
@@ -17,7 +26,15 @@ pub struct Payload {
     #[serde(skip_serializing_if = "String::is_empty")]
     pub error: String,
     #[serde(skip_serializing_if = "serde_json::Value::is_null")]
-    pub errors: serde_json::Value
+    pub errors: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip)]
+    pub headers: Vec<(String, String)>,
+    #[serde(skip)]
+    pub key: Option<String>,
+    #[serde(skip)]
+    pub cause: Option<Errors>,
 }
 
 // Implement default for Payload
@@ -30,6 +47,10 @@ impl Default for Payload {
             data: serde_json::Value::Null,
             error: String::default(),
             errors: serde_json::Value::Null,
+            request_id: None,
+            headers: vec![],
+            key: None,
+            cause: None,
         }
     }
 }
@@ -37,7 +58,7 @@ impl Default for Payload {
 // Implement display for Payload
 impl Display for Payload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self)
+        write!(f, "{}", serde_json::to_string(self).unwrap_or_default())
     }
 }
 
@@ -45,27 +66,8 @@ impl Display for Payload {
 impl Responder for Payload {
     type Body = BoxBody;
 
-    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
-        // Set payload
-        let payload = serde_json::to_string(&self.clone()).unwrap();
-
-        // Set code
-        let mut code = 400;
-        match () {
-            _ if self.code.as_ref().is_some() => code = *self.code.as_ref().unwrap() as i32,
-            _ if self.code.as_ref().is_none() && !self.challenge.is_empty() => code = 200,
-            _ => ()
-        }
-
-        // Set response builder
-        match code {
-            200 => HttpResponse::Ok(),
-            401 => HttpResponse::Unauthorized(),
-            404 => HttpResponse::NotFound(),
-            500 => HttpResponse::InternalServerError(),
-            _ => HttpResponse::BadRequest()
-        }.content_type("application/json")
-            .body(payload)
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        problems::respond(req, &self)
     }
 }
 
@@ -144,11 +146,7 @@ impl Payload {
     /// }
     /// ```
     pub fn database() -> Self {
-        let mut payload = Self::default();
-        payload.code = Some(400);
-        payload.error = String::from("Unable to initialize database connection. Please check your server configuration");
-
-        payload
+        Self::localized(400, "database-connection")
     }
 
     /// Creates a new payload with error message
@@ -170,6 +168,29 @@ impl Payload {
         payload
     }
 
+    /// Creates a new payload with the error of a message key, translated by key for the request locale when responding
+    ///
+    /// Example
+    /// ```
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     let payload = Payload::localized(404, "page-not-found");
+    ///     assert_eq!(payload.error, "Page Not Found");
+    ///     assert_eq!(payload.key.as_deref(), Some("page-not-found"));
+    /// }
+    /// ```
+    pub fn localized<T: Into<String>>(code: u16, key: T) -> Self {
+        let key = key.into();
+
+        Self {
+            code: Some(code),
+            error: locales::message(&key),
+            key: Some(key),
+            ..Self::default()
+        }
+    }
+
     /// Creates a new payload with success message
     ///
     /// Example
@@ -219,11 +240,110 @@ impl Payload {
     /// }
     /// ```
     pub fn page_not_found() -> Self {
-        let mut payload = Self::default();
-        payload.code = Some(404);
-        payload.error = String::from("Page Not Found");
+        Self::localized(404, "page-not-found")
+    }
 
-        payload
+    /// Set extra response header
+    ///
+    /// Example
+    /// ```
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     let mut payload = Payload::new(202);
+    ///     payload.set_header("X-Job-Id", "job-12345");
+    /// }
+    /// ```
+    pub fn set_header<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) -> &mut Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set `Location` header, e.g. for 201 Created or redirects
+    ///
+    /// Example
+    /// ```
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     let mut payload = Payload::new(201);
+    ///     payload.set_location("/users/id-12345");
+    /// }
+    /// ```
+    pub fn set_location<T: Into<String>>(&mut self, location: T) -> &mut Self {
+        self.set_header("Location", location)
+    }
+
+    /// Set `Retry-After` header in seconds, e.g. for 429 Too Many Requests or 503 Service Unavailable
+    ///
+    /// Example
+    /// ```
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     let mut payload = Payload::error("Too many requests");
+    ///     payload.code = Some(429);
+    ///     payload.set_retry_after(30);
+    /// }
+    /// ```
+    pub fn set_retry_after(&mut self, seconds: u64) -> &mut Self {
+        self.set_header("Retry-After", seconds.to_string())
+    }
+
+    /// Retrieve response status from code. Without code, challenges respond with 200 and everything else with 400
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::http::StatusCode;
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Payload::new(409).status(), StatusCode::CONFLICT);
+    /// }
+    /// ```
+    pub fn status(&self) -> StatusCode {
+        match self.code {
+            Some(code) => StatusCode::from_u16(code).unwrap_or(StatusCode::BAD_REQUEST),
+            None if !self.challenge.is_empty() => StatusCode::OK,
+            None => StatusCode::BAD_REQUEST
+        }
+    }
+
+    /// Convert payload to json http response with its status and headers
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::http::StatusCode;
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     let mut payload = Payload::new(201);
+    ///     payload.set_location("/users/id-12345");
+    ///
+    ///     let response = payload.to_response();
+    ///     assert_eq!(response.status(), StatusCode::CREATED);
+    ///     assert_eq!(response.headers().get("Location").unwrap(), "/users/id-12345");
+    /// }
+    /// ```
+    pub fn to_response(&self) -> HttpResponse {
+        let mut builder = HttpResponse::build(self.status());
+        for (name, value) in self.headers.iter() {
+            builder.insert_header((name.as_str(), value.as_str()));
+        }
+
+        let mut response = builder.content_type("application/json")
+            .body(serde_json::to_string(self).unwrap());
+
+        if let Some(key) = self.key.as_ref() {
+            response.extensions_mut().insert(MessageKey(key.clone()));
+        }
+
+        // Keep error so Problem Details are typed by its category
+        if let Some(cause) = self.cause.as_ref() {
+            response.extensions_mut().insert(cause.clone());
+        }
+
+        response
     }
 
     /// Creates a new payload instance for invalid permission
@@ -238,13 +358,41 @@ impl Payload {
     /// }
     /// ```
     pub fn permission() -> HttpResponse {
-        let mut payload = Self::default();
-        payload.code = Some(400);
-        payload.error = String::from("Your account does not have enough permission to perform this task");
+        Self::localized(400, "permission-denied").to_response()
+    }
+
+    /// Creates a new http response for forbidden access
+    ///
+    /// Example
+    /// ```
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     // Initialize new payload with HttpResponse type json output
+    ///     let payload = Payload::forbidden();
+    /// }
+    /// ```
+    pub fn forbidden() -> HttpResponse {
+        Self::localized(403, "forbidden").to_response()
+    }
+
+    /// Creates a new http response for missing or outdated multi-factor authentication.
+    /// The `mfa` challenge tells the frontend to ask the user to re-authenticate
+    ///
+    /// Example
+    /// ```
+    /// use library::Payload;
+    ///
+    /// fn main() {
+    ///     // Initialize new payload with HttpResponse type json output
+    ///     let payload = Payload::mfa_required();
+    /// }
+    /// ```
+    pub fn mfa_required() -> HttpResponse {
+        let mut payload = Self::localized(401, "mfa-required");
+        payload.challenge = String::from("mfa");
 
-        HttpResponse::BadRequest()
-            .content_type("application/json")
-            .body(serde_json::to_string(&payload).unwrap())
+        payload.to_response()
     }
 
     /// Creates a new http response for database connection error
@@ -259,13 +407,7 @@ impl Payload {
     /// }
     /// ```
     pub fn database_connection() -> HttpResponse {
-        let mut payload = Self::default();
-        payload.code = Some(400);
-        payload.error = String::from("Unable to initialize database connection. Please check your server configuration");
-
-        HttpResponse::BadRequest()
-            .content_type("application/json")
-            .body(serde_json::to_string(&payload).unwrap())
+        Self::localized(400, "database-connection").to_response()
     }
 
     /// Creates a new http response for expired token
@@ -280,13 +422,7 @@ impl Payload {
     /// }
     /// ```
     pub fn expired_token() -> HttpResponse {
-        let mut payload = Self::default();
-        payload.code = Some(401);
-        payload.error = String::from("Your authentication token has expired");
-
-        HttpResponse::BadRequest()
-            .content_type("application/json")
-            .body(serde_json::to_string(&payload).unwrap())
+        Self::localized(401, "expired-token").to_response()
     }
 
     /// Creates a new http response for invalid server configuration
@@ -301,13 +437,7 @@ impl Payload {
     /// }
     /// ```
     pub fn invalid_server_config() -> HttpResponse {
-        let mut payload = Self::default();
-        payload.code = Some(400);
-        payload.error = String::from("Invalid server configuration. Please contact your server administrator for more info");
-
-        HttpResponse::BadRequest()
-            .content_type("application/json")
-            .body(serde_json::to_string(&payload).unwrap())
+        Self::localized(400, "invalid-configuration").to_response()
     }
 
     /// Creates a new http response for invalid token
@@ -322,13 +452,7 @@ impl Payload {
     /// }
     /// ```
     pub fn invalid_authentication_token() -> HttpResponse {
-        let mut payload = Self::default();
-        payload.code = Some(400);
-        payload.error = String::from("Invalid authentication token");
-
-        HttpResponse::BadRequest()
-            .content_type("application/json")
-            .body(serde_json::to_string(&payload).unwrap())
+        Self::localized(400, "invalid-authentication-token").to_response()
     }
 
     /// Creates a new http response for invalid refresh token
@@ -343,13 +467,7 @@ impl Payload {
     /// }
     /// ```
     pub fn invalid_refresh_token() -> HttpResponse {
-        let mut payload = Self::default();
-        payload.code = Some(400);
-        payload.error = String::from("Invalid refresh token");
-
-        HttpResponse::BadRequest()
-            .content_type("application/json")
-            .body(serde_json::to_string(&payload).unwrap())
+        Self::localized(400, "invalid-refresh-token").to_response()
     }
 
     /// Creates a new http response for invalid web token
@@ -364,13 +482,7 @@ impl Payload {
     /// }
     /// ```
     pub fn invalid_web_token() -> HttpResponse {
-        let mut payload = Self::default();
-        payload.code = Some(400);
-        payload.error = String::from("Invalid web token");
-
-        HttpResponse::BadRequest()
-            .content_type("application/json")
-            .body(serde_json::to_string(&payload).unwrap())
+        Self::localized(400, "invalid-web-token").to_response()
     }
 }
 
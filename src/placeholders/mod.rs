@@ -5,4 +5,40 @@ pub mod tokens;
 pub use files::File;
 pub use socials::Facebook;
 pub use socials::Google;
-pub use tokens::Token;
\ No newline at end of file
+pub use tokens::Token;
+pub use sanitizer::prelude::Sanitize;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Errors;
+
+/// Convert serializable input into T through json, used by `#[derive(Placeholder)]`
+///
+/// Example
+/// ```
+/// use library::placeholders;
+/// use library::Token;
+/// use serde_json::json;
+///
+/// fn main() {
+///     let token: Token = placeholders::convert(&json!({ "access": "ABC1234" }), "Token").unwrap();
+///     assert_eq!(token.access.unwrap(), "ABC1234");
+/// }
+/// ```
+pub fn convert<I, T>(input: &I, label: &str) -> Result<T, Errors>
+    where I: Serialize + ?Sized,
+          T: DeserializeOwned
+{
+    let value = serde_json::to_value(input)
+        .map_err(|error| Errors::new(format!("Unable to serialize {}: {}", label, error)))?;
+
+    serde_json::from_value(value)
+        .map_err(|error| Errors::new(format!("Unable to convert {}: {}", label, error)))
+}
+
+/// Parse json string into T, used by `#[derive(Placeholder)]`
+pub fn parse<T: DeserializeOwned>(input: &str, label: &str) -> Result<T, Errors> {
+    serde_json::from_str(input)
+        .map_err(|error| Errors::new(format!("Unable to convert {}: {}", label, error)))
+}
@@ -2,8 +2,23 @@ use sanitizer::prelude::*;
 use serde::{Serialize, Deserialize};
 use std::default::Default;
 
+use crate::Placeholder;
+
 /// Struct container for token
-#[derive(Debug, Clone, PartialEq, Sanitize, Serialize, Deserialize)]
+///
+/// Example
+/// ```
+/// use library::Token;
+///
+/// fn main() {
+///     let mut token = Token::from_string(r#"{"access": " ABC1234 ", "refresh": "ABC1234"}"#);
+///     token.normalize();
+///
+///     assert_eq!(token.access.as_deref(), Some("ABC1234"));
+///     assert!(Token::new().is_empty());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Placeholder, Sanitize, Serialize, Deserialize)]
 pub struct Token {
     #[sanitize(trim)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -38,145 +53,6 @@ impl Default for Token {
 
 /// Token implementation
 impl Token {
-    /// Create new Token instance
-    ///
-    /// Example
-    /// ```
-    /// use library::Token;
-    ///
-    /// fn main() {
-    ///     let token = Token::new();
-    /// }
-    /// ```
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Convert custom struct type to Token
-    ///
-    /// Example
-    /// ```
-    /// use library::Token;
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize)]
-    /// pub struct Token2 {
-    ///     #[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub access: Option<String>,
-    /// 	#[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub refresh: Option<String>,
-    /// }
-    ///
-    /// fn main() {
-    ///     let token = Token::from(Token2{access: Some(String::from("token1.txt")), refresh: None});
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn from<T>(input: T) -> Self
-        where T: Serialize
-    {
-        let s = serde_json::to_string(&input).unwrap_or(String::default());
-        serde_json::from_str(&s).unwrap_or(Token::default())
-    }
-
-    /// Convert custom struct type to Token
-    ///
-    /// Example
-    /// ```
-    /// use library::Token;
-    ///
-    /// fn main() {
-    ///     let input = r#"{"access": "ABC1234", "refresh": "ABC1234"}"#;
-    ///     let token = Token::from_string(input);
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn from_string<T: Into<String>>(input: T) -> Self {
-        let bindings = input.into();
-        serde_json::from_str(&bindings).unwrap_or(Token::default())
-    }
-
-    /// Convert custom struct type from Token to T
-    ///
-    /// Example
-    /// ```
-    /// use library::Token;
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// /// Struct container for Token2
-    /// #[derive(Debug, Clone, Serialize, Deserialize)]
-    /// pub struct Token2 {
-    ///     #[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub access: Option<String>,
-    ///     #[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub refresh: Option<String>
-    /// }
-    ///
-    /// /// Note: this is always required. Default implementation for Token
-    /// impl Default for Token2 {
-    ///     fn default() -> Self {
-    ///         Self {
-    ///             access: None,
-    ///             refresh: None
-    ///         }
-    ///     }
-    /// }
-    ///
-    /// fn main() {
-    ///     let input = r#"{"access": "ABC1234", "refresh": "ABC1234"}"#;
-    ///     let token = Token::from_string(input);
-    ///
-    ///     let token2 = token.to::<Token2>();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn to<T>(&self) -> T
-        where T: serde::de::DeserializeOwned + Default
-    {
-        let value = self.clone();
-        let s = serde_json::to_string(&value).unwrap_or(String::default());
-        let response:T = serde_json::from_str(&s).unwrap_or(T::default());
-
-        response
-    }
-
-    /// Check if tokens has no value
-    ///
-    /// Example
-    /// ```
-    /// // Import token
-    /// use library::Token;
-    ///
-    /// fn main() {
-    ///     // Set token
-    ///     let token = Token::new();
-    ///     let is_empty = token.is_empty();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn is_empty(&self) -> bool {
-        self.clone() == Self::default()
-    }
-
-    /// Normalize token by performing sanitation and other important stuff
-    ///
-    /// Example
-    /// ```
-    /// // Import token
-    /// use library::Token;
-    ///
-    /// fn main() {
-    ///     // Set token
-    ///     let mut token = Token::new();
-    ///     let form = token.normalize();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn normalize(&mut self) -> &mut Self {
-        self.sanitize();
-        self
-    }
-
     /// Set error messages
     ///
     /// Example
@@ -0,0 +1,178 @@
+pub mod modes;
+pub mod problems;
+
+use actix_web::{HttpRequest, HttpResponse};
+use actix_web::body::MessageBody;
+use actix_web::dev::ServiceResponse;
+use actix_web::http::header;
+use actix_web::middleware::{ErrorHandlerResponse, ErrorHandlers};
+use actix_web::web::Data;
+
+pub use crate::problems::modes::Mode;
+pub use crate::problems::problems::{Problem, RESERVED};
+
+use crate::correlations::RequestId;
+use crate::locales;
+use crate::{Errors, Payload};
+use crate::payloads::MessageKey;
+
+/// Check if request should receive Problem Details, either by `Mode` in app data or by `Accept` header
+///
+/// Example
+/// ```
+/// use actix_web::test::TestRequest;
+/// use library::problems;
+///
+/// fn main() {
+///     let req = TestRequest::get()
+///         .insert_header(("Accept", "application/problem+json"))
+///         .to_http_request();
+///
+///     assert!(problems::is_preferred(&req));
+/// }
+/// ```
+pub fn is_preferred(req: &HttpRequest) -> bool {
+    let mode = req.app_data::<Data<Mode>>().map(|item| *item.get_ref()).unwrap_or_default();
+    match mode {
+        Mode::Always => true,
+        Mode::Never => false,
+        Mode::Negotiate => req.headers()
+            .get(header::ACCEPT)
+            .and_then(|item| item.to_str().ok())
+            .map(|item| item.contains("application/problem+json"))
+            .unwrap_or(false)
+    }
+}
+
+/// Respond error payload localized by `Accept-Language`, with the exposed request id, and as Problem Details when preferred by the request
+///
+/// Example
+/// ```
+/// use actix_web::test::TestRequest;
+/// use library::{problems, Payload};
+///
+/// #[actix_web::main]
+/// async fn main() {
+///     let req = TestRequest::get().insert_header(("Accept-Language", "ja")).to_http_request();
+///     let res = problems::respond(&req, &Payload::localized(401, "expired-token"));
+///
+///     let body = actix_web::body::to_bytes(res.into_body()).await.unwrap();
+///     let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+///     assert_ne!(body["error"], "Your authentication token has expired");
+/// }
+/// ```
+pub fn respond(req: &HttpRequest, payload: &Payload) -> HttpResponse {
+    if !(payload.status().is_client_error() || payload.status().is_server_error()) {
+        return payload.to_response();
+    }
+
+    // Translate built-in error messages by key
+    let mut payload = payload.clone();
+    if let Some(request_id) = RequestId::find(req).filter(|item| item.is_exposed) {
+        payload.request_id = Some(request_id.id);
+    }
+
+    if let Some(key) = payload.key.clone() {
+        payload.error = locales::translate(req, &key, &[]);
+    }
+
+    let locale = locales::negotiate(req);
+    if locale != locales::catalog(req).default_locale {
+        payload.set_header("Content-Language", locale);
+    }
+
+    match is_preferred(req) {
+        true => {
+            let mut problem = Problem::from_payload(&payload);
+            problem.set_instance(req.path());
+            problem.to_response()
+        },
+        false => payload.to_response()
+    }
+}
+
+/// Localize json payload error response, and convert it into Problem Details when preferred by the request, e.g. guard rejections
+pub fn convert(req: &HttpRequest, response: HttpResponse) -> HttpResponse {
+    // Check if response is a json error
+    let is_json = response.headers()
+        .get(header::CONTENT_TYPE)
+        .map(|item| item == "application/json")
+        .unwrap_or(false);
+
+    let is_error = response.status().is_client_error() || response.status().is_server_error();
+    let is_localized = locales::negotiate(req) != locales::catalog(req).default_locale;
+    let is_exposed = RequestId::find(req).map(|item| item.is_exposed).unwrap_or(false);
+    if !is_json || !is_error || !(is_localized || is_exposed || is_preferred(req)) {
+        return response;
+    }
+
+    // Retrieve payload from body
+    let (head, body) = response.into_parts();
+    let bytes = match body.try_into_bytes() {
+        Ok(bytes) => bytes,
+        Err(body) => return head.set_body(body)
+    };
+
+    let value: serde_json::Value = serde_json::from_slice(&bytes).unwrap_or_default();
+    let mut payload = Payload::new(head.status().as_u16());
+    payload.key = head.extensions().get::<MessageKey>().map(|item| item.0.clone());
+    payload.cause = head.extensions().get::<Errors>().cloned();
+    payload.error = value.get("error").and_then(|item| item.as_str()).unwrap_or_default().to_string();
+    payload.challenge = value.get("challenge").and_then(|item| item.as_str()).unwrap_or_default().to_string();
+    payload.errors = value.get("errors").cloned().unwrap_or_default();
+
+    // Keep extra headers
+    for (name, value) in head.headers().iter() {
+        if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH {
+            payload.set_header(name.as_str(), value.to_str().unwrap_or_default());
+        }
+    }
+
+    respond(req, &payload)
+}
+
+/// Stage middleware converting every json error response, including `Errors` returned from handlers, with `convert`.
+/// Without it, errors rendered outside of the library extractors and guards ignore `Mode`, `Accept` and `Accept-Language`
+///
+/// Example
+/// ```
+/// use actix_web::{test, web, App};
+/// use actix_web::web::Data;
+/// use library::{problems, Errors};
+/// use library::problems::Mode;
+///
+/// async fn find() -> Result<String, Errors> {
+///     Err(Errors::not_found("User not found"))
+/// }
+///
+/// async fn save() -> Result<String, Errors> {
+///     Err(Errors::database("Unable to save user"))
+/// }
+///
+/// #[actix_web::main]
+/// async fn main() {
+///     let app = test::init_service(App::new()
+///         .app_data(Data::new(Mode::Always))
+///         .wrap(problems::stage())
+///         .route("/users/1", web::get().to(find))
+///         .route("/users", web::post().to(save))).await;
+///
+///     let res = test::call_service(&app, test::TestRequest::get().uri("/users/1").to_request()).await;
+///     assert_eq!(res.status(), 404);
+///     assert_eq!(res.headers().get("Content-Type").unwrap(), "application/problem+json");
+///
+///     // Typed by the error category instead of the status reason
+///     let res = test::call_service(&app, test::TestRequest::post().uri("/users").to_request()).await;
+///     let body: serde_json::Value = test::read_body_json(res).await;
+///     assert_eq!(body["status"], 500);
+///     assert_eq!(body["type"], "urn:problem:database");
+/// }
+/// ```
+pub fn stage<B: MessageBody + 'static>() -> ErrorHandlers<B> {
+    ErrorHandlers::new().default_handler(|res: ServiceResponse<B>| {
+        let (req, response) = res.map_into_boxed_body().into_parts();
+        let response = convert(&req, response);
+
+        Ok(ErrorHandlerResponse::Response(ServiceResponse::new(req, response).map_into_right_body()))
+    })
+}
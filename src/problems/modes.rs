@@ -0,0 +1,23 @@
+/// Problem Details mode enum, register with `app_data` to select it for the whole app
+///
+/// Example
+/// ```
+/// use actix_web::App;
+/// use actix_web::web::Data;
+/// use library::problems::Mode;
+///
+/// fn main() {
+///     // Always respond errors as application/problem+json
+///     App::new().app_data(Data::new(Mode::Always));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Mode {
+    /// Use Problem Details when `Accept` asks for `application/problem+json`
+    #[default]
+    Negotiate,
+    /// Always use Problem Details for errors
+    Always,
+    /// Never use Problem Details
+    Never,
+}
@@ -0,0 +1,173 @@
+use actix_web::HttpResponse;
+use actix_web::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::Errors;
+use crate::Payload;
+
+/// Members defined by RFC 7807, never overwritten by extensions
+pub const RESERVED: &[&str] = &["type", "title", "status", "detail", "instance"];
+
+/// Struct container for RFC 7807 Problem Details
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Problem {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(flatten)]
+    pub extensions: Map<String, Value>,
+    #[serde(skip)]
+    pub headers: Vec<(String, String)>,
+}
+
+/// Default implementation for Problem
+impl Default for Problem {
+    fn default() -> Self {
+        Self::new(StatusCode::BAD_REQUEST)
+    }
+}
+
+/// Problem implementation
+impl Problem {
+    /// Creates Problem for status, typed by the status reason, e.g. `urn:problem:not-found`
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::http::StatusCode;
+    /// use library::Problem;
+    ///
+    /// fn main() {
+    ///     let problem = Problem::new(StatusCode::NOT_FOUND);
+    ///     assert_eq!(problem.problem_type, "urn:problem:not-found");
+    /// }
+    /// ```
+    pub fn new(status: StatusCode) -> Self {
+        let title = status.canonical_reason().unwrap_or("Unknown Error").to_string();
+        let slug = title.to_lowercase().replace(' ', "-");
+
+        Self {
+            problem_type: format!("urn:problem:{}", slug),
+            title,
+            status: status.as_u16(),
+            detail: None,
+            instance: None,
+            extensions: Map::new(),
+            headers: vec![],
+        }
+    }
+
+    /// Creates Problem from payload, keeping `errors`, `challenge` and `request_id` as extensions.
+    /// Payloads of an `Errors` response are typed by the error category, see `from_error`
+    ///
+    /// Example
+    /// ```
+    /// use library::{Payload, Problem};
+    ///
+    /// fn main() {
+    ///     let problem = Problem::from_payload(&Payload::error("Invalid request"));
+    ///     assert_eq!(problem.status, 400);
+    ///     assert_eq!(problem.detail.unwrap(), "Invalid request");
+    /// }
+    /// ```
+    pub fn from_payload(payload: &Payload) -> Self {
+        let mut problem = match payload.cause.as_ref() {
+            Some(error) => Self::from_error(error),
+            None => Self::new(payload.status())
+        };
+
+        problem.detail = (!payload.error.is_empty()).then(|| payload.error.clone());
+        problem.headers = payload.headers.clone();
+
+        if !payload.errors.is_null() {
+            problem.set_extension("errors", payload.errors.clone());
+        }
+
+        if !payload.challenge.is_empty() {
+            problem.set_extension("challenge", Value::String(payload.challenge.clone()));
+        }
+
+        if let Some(request_id) = payload.request_id.as_ref() {
+            problem.set_extension("request_id", Value::String(request_id.clone()));
+        }
+
+        problem
+    }
+
+    /// Creates Problem from error, typed by the error category, e.g. `urn:problem:validation`
+    ///
+    /// Example
+    /// ```
+    /// use library::{Errors, Problem};
+    ///
+    /// fn main() {
+    ///     let error = Errors::validation("Invalid form", vec![("email", "Email is required")]);
+    ///     let problem = Problem::from_error(&error);
+    ///     assert_eq!(problem.problem_type, "urn:problem:validation");
+    ///     assert_eq!(problem.status, 422);
+    /// }
+    /// ```
+    pub fn from_error(error: &Errors) -> Self {
+        let mut problem = Self::new(actix_web::ResponseError::status_code(error));
+        problem.problem_type = format!("urn:problem:{}", error.category());
+        problem.detail = Some(error.to_string());
+
+        if let Some(fields) = error.fields() {
+            problem.set_extension("errors", serde_json::to_value(fields).unwrap_or(Value::Null));
+        }
+
+        problem
+    }
+
+    /// Set request path the problem occurred on
+    pub fn set_instance<T: Into<String>>(&mut self, instance: T) -> &mut Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    /// Set extension member, reserved members (`type`, `title`, `status`, `detail`, `instance`) are ignored
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::http::StatusCode;
+    /// use library::Problem;
+    /// use serde_json::json;
+    ///
+    /// fn main() {
+    ///     let mut problem = Problem::new(StatusCode::FORBIDDEN);
+    ///     problem.set_extension("status", json!(200)).set_extension("balance", json!(30));
+    ///
+    ///     assert_eq!(problem.status, 403);
+    ///     assert!(problem.extensions.get("status").is_none());
+    ///     assert_eq!(problem.extensions["balance"], 30);
+    /// }
+    /// ```
+    pub fn set_extension<T: Into<String>>(&mut self, key: T, value: Value) -> &mut Self {
+        let key = key.into();
+        if !RESERVED.contains(&key.as_str()) {
+            self.extensions.insert(key, value);
+        }
+
+        self
+    }
+
+    /// Convert problem to `application/problem+json` http response
+    pub fn to_response(&self) -> HttpResponse {
+        let mut builder = HttpResponse::build(StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_REQUEST));
+        for (name, value) in self.headers.iter() {
+            builder.insert_header((name.as_str(), value.as_str()));
+        }
+
+        // Drop reserved members added to extensions directly
+        let mut problem = self.clone();
+        problem.extensions.retain(|key, _| !RESERVED.contains(&key.as_str()));
+
+        builder.content_type("application/problem+json")
+            .body(serde_json::to_string(&problem).unwrap())
+    }
+}
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+/// Replacement of secrets, fixed so the length is not revealed
+pub const SECRET: &str = "********";
+
+/// Struct container for format aware maskers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Masker {
+    /// Keeps the first and last characters, e.g. `j*****e`
+    #[default]
+    Partial,
+    /// Masks the name of every email address and keeps the domain, e.g. `j*****e@gmail.com`
+    Email,
+    /// Keeps the leading `+`, separators and last 4 digits, e.g. `+** *** *** 4567`
+    Phone,
+    /// Keeps separators and last 4 digits, e.g. `**** **** **** 1111`
+    Card,
+    /// Keeps the `Bearer` or `Basic` scheme and first 4 characters, e.g. `v2.l********`
+    Token,
+    /// Replaces the whole value, e.g. `********`
+    Secret,
+}
+
+/// Masker implementation
+impl Masker {
+    /// Mask value, empty values stay empty
+    ///
+    /// Example
+    /// ```
+    /// use library::redactions::Masker;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Masker::Partial.mask("ñandú"), "ñ***ú");
+    ///     assert_eq!(Masker::Email.mask("John <johndoe@gmail.com>"), "John <j*****e@gmail.com>");
+    ///     assert_eq!(Masker::Phone.mask("+63 917 123 4567"), "+** *** *** 4567");
+    ///     assert_eq!(Masker::Card.mask("4111-1111-1111-1111"), "****-****-****-1111");
+    ///     assert_eq!(Masker::Token.mask("v2.local.c2VjcmV0"), "v2.l********");
+    ///     assert_eq!(Masker::Token.mask("Bearer v2.local.c2VjcmV0"), "Bearer v2.l********");
+    ///     assert_eq!(Masker::Secret.mask("hunter2"), "********");
+    ///     assert_eq!(Masker::Partial.mask(""), "");
+    /// }
+    /// ```
+    pub fn mask(&self, value: &str) -> String {
+        if value.is_empty() {
+            return String::new();
+        }
+
+        match self {
+            Masker::Partial => partial(value),
+            Masker::Email => emails(value).unwrap_or_else(|| partial(value)),
+            Masker::Phone | Masker::Card => digits(value),
+            Masker::Token => token(value),
+            Masker::Secret => String::from(SECRET),
+        }
+    }
+
+    /// Detect masker of a value by its format: emails, card numbers passing the Luhn check and paseto or bearer tokens
+    ///
+    /// Example
+    /// ```
+    /// use library::redactions::Masker;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Masker::detect("reach me at jane@example.com"), Some(Masker::Email));
+    ///     assert_eq!(Masker::detect("4111 1111 1111 1111"), Some(Masker::Card));
+    ///     assert_eq!(Masker::detect("Bearer v2.local.c2VjcmV0"), Some(Masker::Token));
+    ///     assert_eq!(Masker::detect("2024-01-31"), None);
+    /// }
+    /// ```
+    pub fn detect(value: &str) -> Option<Masker> {
+        let value = value.trim();
+        let lowercase = value.to_lowercase();
+
+        if ["bearer ", "basic ", "v1.", "v2.", "v3.", "v4."].iter().any(|prefix| lowercase.starts_with(prefix)) && value.len() > 16 {
+            return Some(Masker::Token);
+        }
+
+        if is_card(value) {
+            return Some(Masker::Card);
+        }
+
+        emails(value).map(|_| Masker::Email)
+    }
+}
+
+// Keep the first and last characters
+fn partial(value: &str) -> String {
+    let length = value.chars().count();
+
+    value.chars()
+        .enumerate()
+        .map(|(index, c)| match index == 0 || index + 1 == length {
+            true => c,
+            false => '*'
+        })
+        .collect()
+}
+
+// Mask the name of each email address found in the value, `None` when there is none
+fn emails(value: &str) -> Option<String> {
+    let mut found = false;
+    let mut result = String::with_capacity(value.len());
+    let mut word = String::new();
+
+    for c in value.chars().chain(std::iter::once('\0')) {
+        if !c.is_whitespace() && !matches!(c, '<' | '>' | ',' | ';' | '(' | ')' | '"' | '\'' | '\0') {
+            word.push(c);
+            continue;
+        }
+
+        match word.rsplit_once('@') {
+            Some((name, domain)) if !name.is_empty() && domain.contains('.') => {
+                found = true;
+                result.push_str(&partial(name));
+                result.push('@');
+                result.push_str(domain);
+            },
+            _ => result.push_str(&word)
+        }
+
+        word.clear();
+        if c != '\0' {
+            result.push(c);
+        }
+    }
+
+    found.then_some(result)
+}
+
+// Keep the authorization scheme and first 4 characters of the credentials
+fn token(value: &str) -> String {
+    if let Some((scheme, credentials)) = value.split_once(' ') {
+        if scheme.eq_ignore_ascii_case("bearer") || scheme.eq_ignore_ascii_case("basic") {
+            return format!("{} {}", scheme, token(credentials.trim()));
+        }
+    }
+
+    match value.chars().count() > 8 {
+        true => format!("{}{}", value.chars().take(4).collect::<String>(), SECRET),
+        false => String::from(SECRET)
+    }
+}
+
+// Mask digits except the last 4, keeping separators and a leading `+`
+fn digits(value: &str) -> String {
+    let total = value.chars().filter(char::is_ascii_digit).count();
+    let kept = match total > 4 {
+        true => 4,
+        false => 0
+    };
+
+    let mut seen = 0;
+    value.chars()
+        .map(|c| match c.is_ascii_digit() {
+            true => {
+                seen += 1;
+                match seen > total - kept {
+                    true => c,
+                    false => '*'
+                }
+            },
+            false => c
+        })
+        .collect()
+}
+
+// Check if value is a 13 to 19 digit card number passing the Luhn check
+fn is_card(value: &str) -> bool {
+    if !value.chars().all(|c| c.is_ascii_digit() || c == ' ' || c == '-') {
+        return false;
+    }
+
+    let digits: Vec<u32> = value.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() < 13 || digits.len() > 19 {
+        return false;
+    }
+
+    let sum: u32 = digits.iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| match index % 2 == 1 {
+            true if digit * 2 > 9 => digit * 2 - 9,
+            true => digit * 2,
+            false => *digit
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
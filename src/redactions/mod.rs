@@ -0,0 +1,42 @@
+pub mod maskers;
+pub mod redactors;
+
+pub use crate::redactions::maskers::{Masker, SECRET};
+pub use crate::redactions::redactors::Redactor;
+
+/// Trait for structs holding credentials or personal data, producing masked copies safe for logs and debug output
+///
+/// Example
+/// ```
+/// use library::Mailer;
+/// use library::redactions::Redact;
+///
+/// fn main() {
+///     let mut mailer = Mailer::new();
+///     mailer.username = String::from("no-reply@example.com");
+///     mailer.password = String::from("hunter2");
+///
+///     let mailer = mailer.redacted();
+///     assert_eq!(mailer.username, "n******y@example.com");
+///     assert_eq!(mailer.password, "********");
+/// }
+/// ```
+pub trait Redact {
+    /// Create masked copy
+    fn redacted(&self) -> Self;
+}
+
+/// Mask value with the masker detected from its format, other values keep only the first and last characters
+///
+/// Example
+/// ```
+/// use library::redactions;
+///
+/// fn main() {
+///     assert_eq!(redactions::mask("johndoe@gmail.com"), "j*****e@gmail.com");
+///     assert_eq!(redactions::mask("Juan"), "J**n");
+/// }
+/// ```
+pub fn mask(value: &str) -> String {
+    Masker::detect(value).unwrap_or_default().mask(value)
+}
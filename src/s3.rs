@@ -1,10 +1,11 @@
 use image::{GenericImageView, ImageFormat, Rgba};
 use image::imageops::FilterType;
-use infer::Infer;
 use reqwest;
 use rusoto_core::credential::{StaticProvider};
 use rusoto_core::{HttpClient, Region};
-use rusoto_s3::{PutObjectRequest, S3 as RusotoS3, S3Client};
+use actix_web::web::Bytes;
+use futures::future::LocalBoxFuture;
+use rusoto_s3::{AbortMultipartUploadRequest, CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart, CreateMultipartUploadRequest, DeleteObjectRequest, PutObjectRequest, S3 as RusotoS3, S3Client, UploadPartRequest};
 use sanitizer::prelude::*;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
@@ -27,11 +28,27 @@ use bstr::ByteSlice;
 //     objdetect::CascadeClassifier,
 // };
 
-use crate::{Errors, File};
+use crate::{Errors, File, Placeholder};
+use crate::mimes;
+use crate::redactions::{Redact, Redactor};
 use crate::strings;
+use crate::uploads::{Storage, Writer};
 
 /// Struct container for s3
-#[derive(Debug, Clone, PartialEq, Sanitize, Serialize, Deserialize)]
+///
+/// Example
+/// ```
+/// use library::S3;
+///
+/// fn main() {
+///     let s3 = S3::from_string(r#"{"access_key_id": "ABC1234", "secret_access_key": "ABC1234", "bucket": "", "path": "", "region": "", "module_profile_picture": "", "image_small_size": 0, "image_medium_size": 0, "image_large_size": 0, "image_xls_size": 0}"#);
+///     assert_eq!(s3.access_key_id, "ABC1234");
+///     assert!(S3::default().is_empty());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Placeholder, Sanitize, Serialize, Deserialize)]
+#[placeholder(skip(new))]
+#[serde(default)]
 pub struct S3 {
     #[sanitize(trim)]
     #[serde(skip_serializing_if = "String::is_empty")]
@@ -75,6 +92,13 @@ impl Default for S3 {
     }
 }
 
+/// Redact implementation for S3, masks the access key id and secret access key
+impl Redact for S3 {
+    fn redacted(&self) -> Self {
+        Redactor::new().copy(self).unwrap_or_default()
+    }
+}
+
 /// S3 implementation
 impl S3 {
     /// Create new S3 instance
@@ -149,131 +173,6 @@ impl S3 {
         self.image_xls_size = item.clone().image_xls_size;
     }
 
-    /// Convert custom struct type to S3
-    ///
-    /// Example
-    /// ```
-    /// use library::S3;
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize)]
-    /// pub struct S32 {
-    ///     #[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub access_key_id: Option<String>,
-    /// 	#[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub secret_access_key: Option<String>,
-    /// }
-    ///
-    /// fn main() {
-    ///     let s3 = S3::from(S32{access_key_id: Some(String::from("AccessKeyIDHere")), secret_access_key: None});
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn from<T>(input: T) -> Self
-        where T: Serialize
-    {
-        let s = serde_json::to_string(&input).unwrap_or(String::default());
-        serde_json::from_str(&s).unwrap_or(S3::default())
-    }
-
-    /// Convert custom struct type to S3
-    ///
-    /// Example
-    /// ```
-    /// use library::S3;
-    ///
-    /// fn main() {
-    ///     let input = r#"{"access_key_id": "ABC1234", "secret_access_key": "ABC1234"}"#;
-    ///     let s3 = S3::from_string(input);
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn from_string<T: Into<String>>(input: T) -> Self {
-        let bindings = input.into();
-        serde_json::from_str(&bindings).unwrap_or(S3::default())
-    }
-
-    /// Convert custom struct type from S3 to T
-    ///
-    /// Example
-    /// ```
-    /// use library::S3;
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// /// Struct container for S32
-    /// #[derive(Debug, Clone, Serialize, Deserialize)]
-    /// pub struct S32 {
-    ///     #[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub access_key_id: Option<String>,
-    ///     #[serde(skip_serializing_if = "Option::is_none")]
-    ///     pub secret_access_key: Option<String>
-    /// }
-    ///
-    /// /// Note: this is always required. Default implementation for S3
-    /// impl Default for S32 {
-    ///     fn default() -> Self {
-    ///         Self {
-    ///             access_key_id: None,
-    ///             secret_access_key: None
-    ///         }
-    ///     }
-    /// }
-    ///
-    /// fn main() {
-    ///     let input = r#"{"access_key_id": "ABC1234", "secret_access_key": "ABC1234"}"#;
-    ///     let s3 = S3::from_string(input);
-    ///
-    ///     let s32 = s3.to::<S32>();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn to<T>(&self) -> T
-        where T: serde::de::DeserializeOwned + Default
-    {
-        let value = self.clone();
-        let s = serde_json::to_string(&value).unwrap_or(String::default());
-        let response: T = serde_json::from_str(&s).unwrap_or(T::default());
-
-        response
-    }
-
-    /// Check if s3s has no value
-    ///
-    /// Example
-    /// ```
-    /// // Import s3
-    /// use library::S3;
-    ///
-    /// fn main() {
-    ///     // Set s3
-    ///     let s3 = S3::new();
-    ///     let is_empty = s3.is_empty();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn is_empty(&self) -> bool {
-        self.clone() == Self::default()
-    }
-
-    /// Normalize s3 by performing sanitation and other important stuff
-    ///
-    /// Example
-    /// ```
-    /// // Import s3
-    /// use library::S3;
-    ///
-    /// fn main() {
-    ///     // Set s3
-    ///     let mut s3 = S3::new();
-    ///     let form = s3.normalize();
-    /// }
-    /// ```
-    #[allow(dead_code)]
-    pub fn normalize(&mut self) -> &mut Self {
-        self.sanitize();
-        self
-    }
-
     /// Retrieve client
     ///
     /// Example
@@ -325,7 +224,7 @@ impl S3 {
         // Retrieve client
         let client = self.get_client();
         if client.is_none() {
-            return Err(Errors::new("S3 client failed to initialize"));
+            return Err(Errors::config("S3 client failed to initialize").logged());
         }
 
         // Shadow client
@@ -338,14 +237,14 @@ impl S3 {
 
         // Download the image from the URL
         let response = reqwest::get(url_bindings).await;
-        if response.is_err() {
-            return Err(Errors::new("Unable download file from url"));
+        if let Err(error) = response {
+            return Err(Errors::external("Unable download file from url").with_source(error).logged());
         }
 
         // Check bytes
         let response = response.unwrap().bytes().await;
-        if response.is_err() {
-            return Err(Errors::new("Unable to download file from url"));
+        if let Err(error) = response {
+            return Err(Errors::external("Unable to download file from url").with_source(error).logged());
         }
 
         // Create image buffer
@@ -353,10 +252,7 @@ impl S3 {
         let buffer = cursor.get_ref();
 
         // Check out mime type
-        let info = Infer::new();
-        let mime = info
-            .get(&buffer.clone())
-            .map_or(String::default(), |t| String::from(t.mime_type()));
+        let mime = mimes::detect(buffer, Some(&filename));
 
         // Retrieve content type
         let extension = strings::get_extension_from_mime(&mime);
@@ -379,8 +275,8 @@ impl S3 {
 
         // Upload file
         let result = client.put_object(request).await;
-        if result.is_err() {
-            return Err(Errors::new("Unable to upload your file"));
+        if let Err(error) = result {
+            return Err(Errors::external("Unable to upload your file").with_source(error).logged());
         }
 
         Ok(())
@@ -393,7 +289,7 @@ impl S3 {
         // Retrieve client
         let client = self.get_client();
         if client.is_none() {
-            return Err(Errors::new("S3 client failed to initialize"));
+            return Err(Errors::config("S3 client failed to initialize").logged());
         }
 
         // Shadow client
@@ -406,10 +302,7 @@ impl S3 {
         let filename = strings::replace_filename(file_name_bindings, "original");
 
         // Check out mime type
-        let info = Infer::new();
-        let mime = info
-            .get(&data.clone())
-            .map_or(String::default(), |t| String::from(t.mime_type()));
+        let mime = mimes::detect(&data, Some(&filename));
 
         // Set metadata
         let mut metadata = HashMap::new();
@@ -428,8 +321,8 @@ impl S3 {
 
         // Upload file
         let result = client.put_object(request).await;
-        if result.is_err() {
-            return Err(Errors::new("Unable to upload your file"));
+        if let Err(error) = result {
+            return Err(Errors::external("Unable to upload your file").with_source(error).logged());
         }
 
         // Check if current mime type is image
@@ -458,7 +351,7 @@ impl S3 {
         // Retrieve client
         let client = self.get_client();
         if client.is_none() {
-            return Err(Errors::new("S3 client failed to initialize"));
+            return Err(Errors::config("S3 client failed to initialize").logged());
         }
 
         // Shadow client
@@ -469,20 +362,17 @@ impl S3 {
         let buffer = cursor.get_ref();
 
         // Check out mime type
-        let info = Infer::new();
-        let mime = info
-            .get(&buffer.clone())
-            .map_or(String::default(), |t| String::from(t.mime_type()));
+        let mime = mimes::detect(buffer, None);
 
         // Check if data is image
         if !File::is_image(mime) {
-            return Err(Errors::new("Invalid image type"));
+            return Err(Errors::new("Invalid image type").logged());
         }
 
         // Load image from data
         let image = image::load_from_memory(&data);
         if image.is_err() {
-            return Err(Errors::new("Unable to load image"));
+            return Err(Errors::new("Unable to load image").logged());
         }
 
         // Shadow image
@@ -527,18 +417,15 @@ impl S3 {
         // Open the file and read its contents
         let mut cursor = Cursor::new(vec![]);
         let result = thumbnail.write_to(&mut cursor, ImageFormat::Png);
-        if result.is_err() {
-            return Err(Errors::new("Thumbnail generation failed"));
+        if let Err(error) = result {
+            return Err(Errors::config("Thumbnail generation failed").with_source(error).logged());
         }
 
         // Set buffer
         let buffer = cursor.get_ref();
 
-        // Check out mime type
-        let info = Infer::new();
-        let mime = info
-            .get(&data.clone())
-            .map_or(String::default(), |t| String::from(t.mime_type()));
+        // Check out mime type of the generated thumbnail
+        let mime = mimes::detect(buffer, Some(&filename));
 
         // Set metadata
         let mut metadata = HashMap::new();
@@ -557,8 +444,8 @@ impl S3 {
 
         // Upload file
         let result = client.put_object(request).await;
-        if result.is_err() {
-            return Err(Errors::new("Unable to upload your file"));
+        if let Err(error) = result {
+            return Err(Errors::external("Unable to upload your file").with_source(error).logged());
         }
 
         Ok(())
@@ -574,8 +461,8 @@ impl S3 {
 
         // Set path of sample upload
         let stream = StdFile::open(format!("./assets/sample/{}", file_name));
-        if stream.is_err() {
-            return Err(Errors::new(format!("Sample {} not found in path", file_name)));
+        if let Err(error) = stream {
+            return Err(Errors::config(format!("Sample {} not found in path", file_name)).with_source(error).logged());
         }
 
         // Unwrap stream
@@ -584,8 +471,8 @@ impl S3 {
 
         // Read file to end
         let result = stream.read_to_end(&mut contents);
-        if result.is_err() {
-            return Err(Errors::new("Unable to read file"));
+        if let Err(error) = result {
+            return Err(Errors::config("Unable to read file").with_source(error).logged());
         }
 
         // Create vector of width and height
@@ -613,7 +500,7 @@ impl S3 {
         // Retrieve client
         let client = self.get_client();
         if client.is_none() {
-            return Err(Errors::new("S3 client failed to initialize"));
+            return Err(Errors::config("S3 client failed to initialize").logged());
         }
 
         // Shadow client
@@ -621,8 +508,8 @@ impl S3 {
 
         // Set path of sample upload
         let stream = StdFile::open("./assets/sample/doc.txt");
-        if stream.is_err() {
-            return Err(Errors::new("Sample doc.txt not found in path"));
+        if let Err(error) = stream {
+            return Err(Errors::config("Sample doc.txt not found in path").with_source(error).logged());
         }
 
         // Unwrap stream
@@ -631,19 +518,12 @@ impl S3 {
 
         // Read file to end
         let result = stream.read_to_end(&mut contents);
-        if result.is_err() {
-            return Err(Errors::new("Unable to read file"));
+        if let Err(error) = result {
+            return Err(Errors::config("Unable to read file").with_source(error).logged());
         }
 
-        // Set info
-        let info = Infer::new();
-        let mut mime: Option<String> = None;
-
         // Check out mime type
-        let mime_type = info.get(&contents.clone());
-        if mime_type.is_some() && !mime_type.clone().unwrap().mime_type().is_empty() {
-            mime = Some(String::from(mime_type.clone().unwrap().mime_type()));
-        }
+        let mime = Some(mimes::detect(&contents, Some("doc.txt")));
 
         // Upload file
         let req = PutObjectRequest {
@@ -656,8 +536,8 @@ impl S3 {
         };
 
         let result = client.put_object(req).await;
-        if result.is_err() {
-            return Err(Errors::new("Unable to read file"));
+        if let Err(error) = result {
+            return Err(Errors::external("Unable to upload your file").with_source(error).logged());
         }
 
         Ok(())
@@ -675,7 +555,7 @@ impl S3 {
     //     // Retrieve client
     //     let client = self.get_client();
     //     if client.is_none() {
-    //         return Err(Errors::new("S3 client failed to initialize"));
+    //         return Err(Errors::config("S3 client failed to initialize"));
     //     }
     //
     //     // Shadow client
@@ -786,9 +666,171 @@ impl S3 {
     //     // Upload file
     //     let result = client.put_object(request).await;
     //     if result.is_err() {
-    //         return Err(Errors::new("Unable to upload your file"));
+    //         return Err(Errors::external("Unable to upload your file"));
     //     }
     //
     //     Ok(())
     // }
 }
+
+/// Minimum part size of S3 multipart uploads, smaller uploads are sent with a single request
+const PART_SIZE: usize = 5_242_880;
+
+/// Storage implementation for S3, keys are stored inside the S3 path and streamed with multipart uploads
+impl Storage for S3 {
+    fn create(&self, key: &str, mime: &str) -> LocalBoxFuture<'static, Result<Box<dyn Writer>, Errors>> {
+        let client = self.get_client();
+        let bucket = self.bucket.clone();
+        let key = format!("{}/{}", self.path, key);
+        let mime = mime.to_string();
+
+        Box::pin(async move {
+            let client = client.ok_or_else(|| Errors::config("S3 client failed to initialize").logged())?;
+
+            Ok(Box::new(S3Writer { client, bucket, key, mime, buffer: Vec::new(), upload_id: None, parts: Vec::new() }) as Box<dyn Writer>)
+        })
+    }
+
+    fn remove(&self, key: &str) -> LocalBoxFuture<'static, Result<(), Errors>> {
+        let client = self.get_client();
+        let request = DeleteObjectRequest {
+            bucket: self.bucket.clone(),
+            key: format!("{}/{}", self.path, key),
+            ..Default::default()
+        };
+
+        Box::pin(async move {
+            let client = client.ok_or_else(|| Errors::config("S3 client failed to initialize").logged())?;
+            client.delete_object(request).await
+                .map(|_| ())
+                .map_err(|error| Errors::external("Unable to remove your file").with_source(error).logged())
+        })
+    }
+}
+
+/// Struct container for a file being streamed to S3
+pub struct S3Writer {
+    client: S3Client,
+    bucket: String,
+    key: String,
+    mime: String,
+    buffer: Vec<u8>,
+    upload_id: Option<String>,
+    parts: Vec<CompletedPart>,
+}
+
+/// S3Writer implementation
+impl S3Writer {
+    /// Upload buffered bytes as the next part, starting the multipart upload if needed
+    async fn flush(&mut self) -> Result<(), Errors> {
+        let upload_id = match &self.upload_id {
+            Some(upload_id) => upload_id.clone(),
+            None => {
+                let request = CreateMultipartUploadRequest {
+                    bucket: self.bucket.clone(),
+                    key: self.key.clone(),
+                    acl: Some("public-read".to_owned()),
+                    content_type: Some(self.mime.clone()),
+                    ..Default::default()
+                };
+
+                let output = self.client.create_multipart_upload(request).await
+                    .map_err(|error| Errors::external("Unable to upload your file").with_source(error).logged())?;
+                let upload_id = output.upload_id.ok_or_else(|| Errors::external("Unable to upload your file").logged())?;
+
+                self.upload_id = Some(upload_id.clone());
+                upload_id
+            }
+        };
+
+        // Upload part
+        let part_number = self.parts.len() as i64 + 1;
+        let body = std::mem::take(&mut self.buffer);
+        let request = UploadPartRequest {
+            bucket: self.bucket.clone(),
+            key: self.key.clone(),
+            upload_id,
+            part_number,
+            content_length: Some(body.len() as i64),
+            body: Some(body.into()),
+            ..Default::default()
+        };
+
+        let output = self.client.upload_part(request).await
+            .map_err(|error| Errors::external("Unable to upload your file").with_source(error).logged())?;
+
+        self.parts.push(CompletedPart { e_tag: output.e_tag, part_number: Some(part_number) });
+        Ok(())
+    }
+}
+
+/// Writer implementation for S3Writer
+impl Writer for S3Writer {
+    fn write(&mut self, chunk: Bytes) -> LocalBoxFuture<'_, Result<(), Errors>> {
+        Box::pin(async move {
+            self.buffer.extend_from_slice(&chunk);
+            match self.buffer.len() >= PART_SIZE {
+                true => self.flush().await,
+                false => Ok(())
+            }
+        })
+    }
+
+    fn finish(mut self: Box<Self>) -> LocalBoxFuture<'static, Result<(), Errors>> {
+        Box::pin(async move {
+            // Small files are uploaded with a single request
+            if self.upload_id.is_none() {
+                let request = PutObjectRequest {
+                    bucket: self.bucket.clone(),
+                    key: self.key.clone(),
+                    body: Some(std::mem::take(&mut self.buffer).into()),
+                    acl: Some("public-read".to_owned()),
+                    content_type: Some(self.mime.clone()),
+                    ..Default::default()
+                };
+
+                return self.client.put_object(request).await
+                    .map(|_| ())
+                    .map_err(|error| Errors::external("Unable to upload your file").with_source(error).logged());
+            }
+
+            if !self.buffer.is_empty() {
+                if let Err(error) = self.flush().await {
+                    self.abort().await;
+                    return Err(error);
+                }
+            }
+
+            let request = CompleteMultipartUploadRequest {
+                bucket: self.bucket.clone(),
+                key: self.key.clone(),
+                upload_id: self.upload_id.clone().unwrap_or_default(),
+                multipart_upload: Some(CompletedMultipartUpload { parts: Some(self.parts.clone()) }),
+                ..Default::default()
+            };
+
+            // Abort so the uploaded parts are not kept on S3
+            if let Err(error) = self.client.complete_multipart_upload(request).await {
+                self.abort().await;
+                return Err(Errors::external("Unable to upload your file").with_source(error).logged());
+            }
+
+            Ok(())
+        })
+    }
+
+    fn abort(self: Box<Self>) -> LocalBoxFuture<'static, ()> {
+        Box::pin(async move {
+            if let Some(upload_id) = self.upload_id {
+                let request = AbortMultipartUploadRequest {
+                    bucket: self.bucket,
+                    key: self.key,
+                    upload_id,
+                    ..Default::default()
+                };
+
+                let _ = self.client.abort_multipart_upload(request).await;
+            }
+        })
+    }
+}
@@ -1,8 +1,10 @@
 use actix::prelude::*;
 use chrono::Local;
 use cron::Schedule;
+use log::Level;
 use std::{fs, str::FromStr, path::Path, time::Duration, sync::Arc};
 
+use crate::correlations::{self, RequestId};
 use crate::DBPool;
 
 /// Duration timer
@@ -24,11 +26,18 @@ fn delete_old_logs(logs_folder: &Path, expiry: i32, show_logs: bool) {
             false => format!("{} day starting today", expiry.clone()),
         };
 
-        println!("Deleting logs which is > {}...", exp);
+        correlations::log(Level::Info, format!("Deleting logs which is > {}...", exp));
     }
 
-    for entry in fs::read_dir(logs_folder).unwrap() {
-        let entry = entry.unwrap();
+    let entries = match fs::read_dir(logs_folder) {
+        Ok(entries) => entries,
+        Err(error) => {
+            correlations::log(Level::Warn, format!("Unable to read logs folder {:?}: {}", logs_folder, error));
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
         let filename = entry.file_name().to_str().unwrap_or("").to_string();
         if !filename.starts_with("logs.") {
             continue;
@@ -40,7 +49,9 @@ fn delete_old_logs(logs_folder: &Path, expiry: i32, show_logs: bool) {
             let now = Local::now().naive_local();
             let days_old = now.signed_duration_since(date.unwrap()).num_days();
             if days_old >= expiry as i64 {
-                fs::remove_file(entry.path()).unwrap();
+                if let Err(error) = fs::remove_file(entry.path()) {
+                    correlations::log(Level::Warn, format!("Unable to delete log {:?}: {}", entry.path(), error));
+                }
             }
         }
     }
@@ -63,7 +74,7 @@ impl Actor for Scheduler {
     /// Executes start of scheduled task
     fn started(&mut self, ctx: &mut Context<Self>) {
         if self.show_logs {
-            println!("{}", format!("Scheduler for {:?} is now running...", self.duration.clone()));
+            correlations::log(Level::Info, format!("Scheduler for {:?} is now running...", self.duration.clone()));
         }
 
         ctx.run_later(duration_timer(&self.duration), move |this, ctx| {
@@ -74,7 +85,7 @@ impl Actor for Scheduler {
     /// Stop running task
     fn stopped(&mut self, _ctx: &mut Context<Self>) {
         if self.show_logs {
-            println!("{}", format!("Scheduler for {:?} stopped...", self.duration.clone()));
+            correlations::log(Level::Info, format!("Scheduler for {:?} stopped...", self.duration.clone()));
         }
     }
 }
@@ -96,18 +107,21 @@ impl Scheduler {
         }
     }
 
-    /// Execute scheduled task
+    /// Execute scheduled task, each run gets its own request id for logs
     fn schedule_task(&self, ctx: &mut Context<Self>) {
-        // Check if logs were available
-        if self.show_logs {
-            println!("{}", format!("Scheduled task for {:?} executed - {:?}", self.duration.clone(), Local::now()));
-        }
+        let request_id = RequestId::generate();
+        correlations::scope(&request_id.id, || {
+            // Check if logs were available
+            if self.show_logs {
+                correlations::log(Level::Info, format!("Scheduled task for {:?} executed - {:?}", self.duration.clone(), Local::now()));
+            }
 
-        // Delete old logs
-        let logs_folder = Path::new(&self.directory);
-        delete_old_logs(logs_folder, self.expiry, self.show_logs);
+            // Delete old logs
+            let logs_folder = Path::new(&self.directory);
+            delete_old_logs(logs_folder, self.expiry, self.show_logs);
 
-        (self.func)(self.pool.clone());
+            (self.func)(self.pool.clone());
+        });
 
         // Re-run cron
         ctx.run_later(duration_timer(&self.duration), move |this, ctx| {
@@ -0,0 +1,237 @@
+use actix_multipart::MultipartError;
+use actix_multipart::form::MultipartFormConfig;
+use actix_web::HttpRequest;
+use actix_web::error::{InternalError, PathError, PayloadError, QueryPayloadError, UrlencodedError};
+use actix_web::web::{FormConfig, PathConfig, QueryConfig};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::fmt::{Debug, Display};
+
+use crate::locales;
+use crate::Payload;
+use crate::problems;
+
+/// Create staging for url encoded form config, errors share the `json::stage` payload shape
+///
+/// Actix's `Form` only reports the field of missing, unknown and duplicate fields in `errors`, use `ValidatedForm` or `deserialize_form` for the field of type errors
+///
+/// Example
+/// ```
+/// use actix_web::App;
+/// use library::stages;
+///
+/// fn main() {
+///     App::new()
+///         .app_data(stages::form(4096))
+///         .app_data(stages::query())
+///         .app_data(stages::path())
+///         .app_data(stages::multipart(10_485_760, 2_097_152));
+/// }
+/// ```
+pub fn form(form_limit: usize) -> FormConfig {
+    FormConfig::default()
+        .limit(form_limit)
+        .error_handler(|err, req| {
+            let (status, message, field) = match &err {
+                UrlencodedError::ContentType => (400, locales::translate(req, "form-content-type", &[]), None),
+                UrlencodedError::Overflow { .. } => (413, locales::translate(req, "form-overflow", &[]), None),
+                UrlencodedError::Parse(error) => (400, locales::translate(req, "form-deserialize", &[("error", &error.to_string())]), self::field(&error.to_string())),
+                UrlencodedError::Payload(error) => (400, payload_message(req, error), None),
+                _ => (400, locales::translate(req, "request-error", &[]), None),
+            };
+
+            respond(req, err, status, message, field)
+        })
+}
+
+/// Create staging for query string config, errors share the `json::stage` payload shape
+///
+/// Actix's `Query` only reports the field of missing, unknown and duplicate fields in `errors`, use `ValidatedQuery` or `deserialize_query` for the field of type errors
+pub fn query() -> QueryConfig {
+    QueryConfig::default()
+        .error_handler(|err, req| {
+            let (message, field) = match &err {
+                QueryPayloadError::Deserialize(error) => (locales::translate(req, "query-deserialize", &[("error", &error.to_string())]), field(&error.to_string())),
+                _ => (locales::translate(req, "request-error", &[]), None),
+            };
+
+            respond(req, err, 400, message, field)
+        })
+}
+
+/// Create staging for path segments config, errors share the `json::stage` payload shape
+///
+/// Actix's `Path` only reports the field of missing, unknown and duplicate fields in `errors`, type errors (`id` not being a number) have no field
+pub fn path() -> PathConfig {
+    PathConfig::default()
+        .error_handler(|err, req| {
+            let (message, field) = match &err {
+                PathError::Deserialize(error) => (locales::translate(req, "path-deserialize", &[("error", &error.to_string())]), field(&error.to_string())),
+                _ => (locales::translate(req, "request-error", &[]), None),
+            };
+
+            respond(req, err, 400, message, field)
+        })
+}
+
+/// Create staging for multipart form config, errors share the `json::stage` payload shape
+pub fn multipart(total_limit: usize, memory_limit: usize) -> MultipartFormConfig {
+    MultipartFormConfig::default()
+        .total_limit(total_limit)
+        .memory_limit(memory_limit)
+        .error_handler(|err, req| {
+            let (status, message, field) = match &err {
+                MultipartError::ContentTypeMissing |
+                MultipartError::ContentTypeParse |
+                MultipartError::ContentTypeIncompatible |
+                MultipartError::BoundaryMissing => (415, locales::translate(req, "multipart-content-type", &[]), None),
+                MultipartError::MissingField(name) => (400, locales::translate(req, "multipart-missing-field", &[]), Some(name.clone())),
+                MultipartError::DuplicateField(name) => (400, locales::translate(req, "multipart-duplicate-field", &[]), Some(name.clone())),
+                MultipartError::UnknownField(name) => (400, locales::translate(req, "multipart-unknown-field", &[]), Some(name.clone())),
+                MultipartError::Field { name, source } => (
+                    source.as_response_error().status_code().as_u16(),
+                    locales::translate(req, "multipart-field", &[("error", &source.to_string())]),
+                    Some(name.clone())
+                ),
+                MultipartError::Payload(error) => (400, payload_message(req, error), None),
+                _ => (400, locales::translate(req, "multipart-error", &[("error", &err.to_string())]), None),
+            };
+
+            respond(req, err, status, message, field)
+        })
+}
+
+/// Deserialize json value keeping track of the failing field path, responds like `json::stage` with the path in `errors`
+///
+/// Example
+/// ```
+/// use actix_web::test::TestRequest;
+/// use library::stages;
+/// use serde::Deserialize;
+/// use serde_json::{json, Value};
+///
+/// #[derive(Debug, Deserialize)]
+/// pub struct Item {
+///     pub price: f64,
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// pub struct Order {
+///     pub items: Vec<Item>,
+/// }
+///
+/// #[actix_web::main]
+/// async fn main() {
+///     let req = TestRequest::default().to_http_request();
+///     let value = json!({ "items": [{ "price": 1.5 }, { "price": 2 }, { "price": "free" }] });
+///
+///     let error = stages::deserialize::<Order>(&req, value).unwrap_err();
+///     let res = error.error_response();
+///     assert_eq!(res.status(), 400);
+///
+///     let body = actix_web::body::to_bytes(res.into_body()).await.unwrap();
+///     let body: Value = serde_json::from_slice(&body).unwrap();
+///     assert!(body["errors"]["items[2].price"].is_array());
+/// }
+/// ```
+pub fn deserialize<T: DeserializeOwned>(req: &HttpRequest, value: Value) -> Result<T, actix_web::Error> {
+    tracked(req, serde_path_to_error::deserialize(value), "json-deserialize")
+}
+
+/// Deserialize url encoded form body keeping track of the failing field, responds like `form` with the field in `errors`
+///
+/// Example
+/// ```
+/// use actix_web::test::TestRequest;
+/// use library::stages;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize)]
+/// pub struct Search {
+///     pub keyword: String,
+///     pub page: u32,
+/// }
+///
+/// fn main() {
+///     let req = TestRequest::default().to_http_request();
+///
+///     let search = stages::deserialize_form::<Search>(&req, b"keyword=shoes&page=2").unwrap();
+///     assert_eq!(search.page, 2);
+///
+///     let error = stages::deserialize_form::<Search>(&req, b"keyword=shoes&page=two").unwrap_err();
+///     assert_eq!(error.as_response_error().status_code(), 400);
+/// }
+/// ```
+pub fn deserialize_form<T: DeserializeOwned>(req: &HttpRequest, body: &[u8]) -> Result<T, actix_web::Error> {
+    let deserializer = serde_urlencoded::Deserializer::new(form_urlencoded::parse(body));
+    tracked(req, serde_path_to_error::deserialize(deserializer), "form-deserialize")
+}
+
+/// Deserialize the request query string keeping track of the failing field, responds like `query` with the field in `errors`
+pub fn deserialize_query<T: DeserializeOwned>(req: &HttpRequest) -> Result<T, actix_web::Error> {
+    let deserializer = serde_urlencoded::Deserializer::new(form_urlencoded::parse(req.query_string().as_bytes()));
+    tracked(req, serde_path_to_error::deserialize(deserializer), "query-deserialize")
+}
+
+// Respond with the failing field path of a tracked deserialization. Root level errors (".") fall back to the field named by the message
+fn tracked<T, E>(req: &HttpRequest, result: Result<T, serde_path_to_error::Error<E>>, key: &str) -> Result<T, actix_web::Error>
+    where E: Debug + Display + 'static
+{
+    result.map_err(|error| {
+        let path = error.path().to_string();
+        let error = error.into_inner();
+        let message = locales::translate(req, key, &[("error", &error.to_string())]);
+
+        let field = match path.as_str() {
+            "." | "" => field(&error.to_string()),
+            _ => Some(path)
+        };
+
+        respond(req, error, 400, message, field)
+    })
+}
+
+/// Retrieve field named by a serde error message, e.g. "missing field `price`"
+///
+/// Example
+/// ```
+/// use library::stages;
+///
+/// fn main() {
+///     assert_eq!(stages::field("missing field `price`"), Some(String::from("price")));
+///     assert_eq!(stages::field("invalid digit found in string"), None);
+/// }
+/// ```
+pub fn field(message: &str) -> Option<String> {
+    ["missing field `", "unknown field `", "duplicate field `"].iter()
+        .find_map(|prefix| message.find(prefix).map(|index| &message[index + prefix.len()..]))
+        .and_then(|rest| rest.split('`').next())
+        .map(String::from)
+}
+
+/// Create error response for extractor failures, adding the failing field to `errors` when known
+pub fn respond<E>(req: &HttpRequest, error: E, status: u16, message: String, field: Option<String>) -> actix_web::Error
+    where E: Debug + Display + 'static
+{
+    let mut response = Payload::new(status);
+    if let Some(field) = field {
+        response.errors = json!({ field: [message.clone()] });
+    }
+
+    response.error = message;
+
+    InternalError::from_response(error, problems::respond(req, &response)).into()
+}
+
+/// Translate payload read errors shared by the json, form and multipart stages
+pub fn payload_message(req: &HttpRequest, error: &PayloadError) -> String {
+    match error {
+        PayloadError::Incomplete(error) => locales::translate(req, "json-incomplete", &[("error", &error.as_ref().map(|item| item.to_string()).unwrap_or_default())]),
+        PayloadError::EncodingCorrupted => locales::translate(req, "json-encoding-corrupted", &[]),
+        PayloadError::Overflow => locales::translate(req, "json-overflow", &[]),
+        PayloadError::UnknownLength => locales::translate(req, "json-unknown-length", &[]),
+        PayloadError::Http2Payload(error) => error.to_string(),
+        PayloadError::Io(error) => error.to_string(),
+        _ => locales::translate(req, "request-error", &[]),
+    }
+}
@@ -1,5 +1,14 @@
+use deunicode::deunicode;
+use diesel::{QueryableByName, RunQueryDsl};
+use diesel::sql_types::Text;
 use serde_json::Value;
-use titlecase::titlecase;
+use std::collections::HashSet;
+
+use crate::{DBPool, Errors};
+use crate::databases;
+use crate::mimes;
+use crate::names;
+use crate::redactions::Masker;
 
 /// Retrieve extension based on mime string
 ///
@@ -12,80 +21,9 @@ use titlecase::titlecase;
 /// }
 /// ```
 pub fn get_extension_from_mime<T: Into<String>>(value: T) -> String {
-    return match value.into().to_lowercase().as_str() {
-        "audio/aac" => String::from(".aac"),
-        "application/x-abiword" => String::from(".abw"),
-        "application/x-freearc" => String::from(".arc"),
-        "video/x-msvideo" => String::from(".avi"),
-        "application/vnd.amazon.ebook" => String::from(".azw"),
-        "application/octet-stream" => String::from(".bin"),
-        "image/bmp" => String::from(".bmp"),
-        "application/x-bzip2" => String::from(".bz2"),
-        "application/x-csh" => String::from(".csh"),
-        "text/css" => String::from(".css"),
-        "text/csv" => String::from(".csv"),
-        "application/msword" => String::from(".doc"),
-        "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => String::from(".docx"),
-        "application/vnd.ms-fontobject" => String::from(".eot"),
-        "application/epub+zip" => String::from(".epub"),
-        "application/gzip" => String::from(".gz"),
-        "image/gif" => String::from(".gif"),
-        "text/html" => String::from(".html"),
-        "image/vnd.microsoft.icon" => String::from(".ico"),
-        "text/calendar" => String::from(".ics"),
-        "application/java-archive" => String::from(".jar"),
-        "image/jpeg" => String::from(".jpg"),
-        "text/javascript" => String::from(".js"),
-        "application/json" => String::from(".json"),
-        "application/ld+json" => String::from(".jsonld"),
-        "audio/midi" => String::from(".mid"),
-        "audio/x-midi" => String::from(".midi"),
-        "audio/mpeg" => String::from(".mp3"),
-        "video/mpeg" => String::from(".mpeg"),
-        "application/vnd.apple.installer+xml" => String::from(".mpkg"),
-        "application/vnd.oasis.opendocument.presentation" => String::from(".odp"),
-        "application/vnd.oasis.opendocument.spreadsheet" => String::from(".ods"),
-        "application/vnd.oasis.opendocument.text" => String::from(".odt"),
-        "audio/ogg" => String::from(".oga"),
-        "video/ogg" => String::from(".ogv"),
-        "application/ogg" => String::from(".ogx"),
-        "audio/opus" => String::from(".opus"),
-        "font/otf" => String::from(".otf"),
-        "image/png" => String::from(".png"),
-        "application/pdf" => String::from(".pdf"),
-        "application/x-httpd-php" => String::from(".php"),
-        "application/vnd.ms-powerpoint" => String::from(".ppt"),
-        "application/vnd.openxmlformats-officedocument.presentationml.presentation" => String::from(".pptx"),
-        "application/vnd.rar" => String::from(".rar"),
-        "application/rtf" => String::from(".rtf"),
-        "application/x-sh" => String::from(".sh"),
-        "image/svg+xml" => String::from(".svg"),
-        "application/x-shockwave-flash" => String::from(".swf"),
-        "application/x-tar" => String::from(".tar"),
-        "image/tiff" => String::from(".tif"),
-        "video/mp2t" => String::from(".ts"),
-        "font/ttf" => String::from(".ttf"),
-        "text/plain" => String::from(".txt"),
-        "application/vnd.visio" => String::from(".vsd"),
-        "audio/wav" => String::from(".wav"),
-        "audio/webm" => String::from(".weba"),
-        "video/webm" => String::from(".webm"),
-        "image/webp" => String::from(".webp"),
-        "font/woff" => String::from(".woff"),
-        "font/woff2" => String::from(".woff2"),
-        "application/xhtml+xml" => String::from(".xhtml"),
-        "application/vnd.ms-excel" => String::from(".xls"),
-        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => String::from(".xlsx"),
-        "application/xml" => String::from(".xml"),
-        "application/vnd.mozilla.xul+xml" => String::from(".xul"),
-        "application/zip" => String::from(".zip"),
-        "video/3gpp" => String::from(".3gp"),
-        "audio/3gpp" => String::from(".3gp"),
-        "video/3g2" => String::from(".3g2"),
-        "audio/3g2" => String::from(".3g2"),
-        "application/x-7z-compressed" => String::from(".7z"),
-        _ => String::new()
-    }
+    mimes::extension(&value.into())
+        .map(|extension| format!(".{}", extension))
+        .unwrap_or_default()
 }
 
 /// Checks if string from json::Value is empty
@@ -238,7 +176,7 @@ pub fn is_alphanumeric<T: Into<String>>(value: T) -> bool {
     false
 }
 
-/// Mask a given string
+/// Mask a given string, keeping its first and last characters. See `redactions` for format aware maskers
 ///
 /// Example:
 /// ```
@@ -246,27 +184,16 @@ pub fn is_alphanumeric<T: Into<String>>(value: T) -> bool {
 ///
 /// fn main() {
 ///     let result = strings::mask_string("johndoe@gmail.com");
+///     assert_eq!(result, "j***************m");
+///     assert_eq!(strings::mask_string("ñandú"), "ñ***ú");
+///     assert_eq!(strings::mask_string(""), "");
 /// }
 /// ```
-pub fn mask_string<T: Into<String> + Copy>(value: T) -> String {
-    // Create default variables
-    let mut str = String::new();
-    let binding = value.into();
-    let len = binding.as_str().len();
-
-    // Loop through characters
-    for (i, c) in binding.chars().enumerate() {
-        if i == 0 || i == (len - 1) {
-            str = format!("{}{}", str, c);
-        } else {
-            str = format!("{}{}", str, "*");
-        }
-    }
-
-    str
+pub fn mask_string<T: Into<String>>(value: T) -> String {
+    Masker::Partial.mask(&value.into())
 }
 
-/// Normalize name
+/// Normalize name with the default `names::Rules` (particles, prefixes like `Mc` and `O'`, hyphens and suffixes)
 ///
 /// Example:
 /// ```
@@ -274,53 +201,13 @@ pub fn mask_string<T: Into<String> + Copy>(value: T) -> String {
 ///
 /// fn main() {
 ///     let result = strings::normalize_name("john doe");
+///     assert_eq!(result, "John Doe");
+///     assert_eq!(strings::normalize_name("henry viii"), "Henry VIII");
+///     assert_eq!(strings::normalize_name("maria o'brien de la cruz"), "Maria O'Brien de la Cruz");
 /// }
 /// ```
 pub fn normalize_name<T: Into<String>>(value: T) -> String {
-    let bindings = value.into();
-
-    if bindings.is_empty() {
-        return String::default();
-    }
-
-    // Create string vector
-    let mut name_vector = Vec::new();
-
-    // Split string
-    let name_split = bindings.split(" ");
-
-    // Loop through name split
-    for row in name_split {
-        // Set item
-        let item = titlecase(row.clone().to_lowercase().as_str());
-
-        match item.clone().as_str() {
-            "." => name_vector.push(String::from("")),
-            "Jr." => name_vector.push(String::from("Jr")),
-            "Sr." => name_vector.push(String::from("Sr")),
-            "Ii" => name_vector.push(String::from("II")),
-            "Iii" => name_vector.push(String::from("III")),
-            "Iv" => name_vector.push(String::from("IV")),
-            "Vi" => name_vector.push(String::from("VI")),
-            "Vii" => name_vector.push(String::from("VII")),
-            "Viii" => name_vector.push(String::from("VIII")),
-            "Ix" => name_vector.push(String::from("Ix")),
-            "Xi" => name_vector.push(String::from("XI")),
-            "Xii" => name_vector.push(String::from("XII")),
-            "Xiii" => name_vector.push(String::from("XIII")),
-            "Xiv" => name_vector.push(String::from("XIV")),
-            "Xv" => name_vector.push(String::from("XV")),
-            "Xvi" => name_vector.push(String::from("XVI")),
-            "Xvii" => name_vector.push(String::from("XVII")),
-            "Xviii" => name_vector.push(String::from("XVIII")),
-            "Xix" => name_vector.push(String::from("XIX")),
-            "Xx" => name_vector.push(String::from("XX")),
-            s=> name_vector.push(s.clone().to_string())
-        }
-
-    }
-
-    name_vector.clone().join(" ").to_string()
+    names::normalize(&value.into())
 }
 
 /// Modifies filename to add marker on each file
@@ -403,3 +290,172 @@ pub fn rfind_utf8<S: Into<String>, C: Into<char>>(s: S, chr: C) -> Option<usize>
         None
     }
 }
+
+/// Transliterate accents and non-latin scripts to ascii
+///
+/// Example:
+/// ```
+/// use library::strings;
+///
+/// fn main() {
+///     assert_eq!(strings::transliterate("Crème Brûlée"), "Creme Brulee");
+///     assert_eq!(strings::transliterate("Москва"), "Moskva");
+/// }
+/// ```
+pub fn transliterate<T: Into<String>>(value: T) -> String {
+    deunicode(&value.into())
+}
+
+/// Create url slug using `-` as separator
+///
+/// Example:
+/// ```
+/// use library::strings;
+///
+/// fn main() {
+///     assert_eq!(strings::slugify("  Ñandú & Café: 10% off!  "), "nandu-cafe-10-off");
+///     assert_eq!(strings::slugify("Ελληνικά νέα"), "ellenika-nea");
+/// }
+/// ```
+pub fn slugify<T: Into<String>>(value: T) -> String {
+    slugify_with(value, "-", 0)
+}
+
+/// Create url slug with custom separator and max length (0 for no limit), words are not cut unless the first word is too long
+///
+/// Example:
+/// ```
+/// use library::strings;
+///
+/// fn main() {
+///     assert_eq!(strings::slugify_with("The Quick Brown Fox", "_", 15), "the_quick_brown");
+///     assert_eq!(strings::slugify_with("Supercalifragilistic", "-", 10), "supercalif");
+/// }
+/// ```
+pub fn slugify_with<T, S>(value: T, separator: S, max_length: usize) -> String
+    where T: Into<String>,
+          S: Into<String>
+{
+    // Create bindings
+    let separator = separator.into();
+    let words: Vec<String> = transliterate(value)
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(String::from)
+        .collect();
+
+    let slug = words.join(&separator);
+    if max_length == 0 || slug.len() <= max_length {
+        return slug;
+    }
+
+    // Keep whole words that fit
+    let mut result = String::new();
+    for word in words.iter() {
+        let length = match result.is_empty() {
+            true => word.len(),
+            false => result.len() + separator.len() + word.len()
+        };
+
+        if length > max_length {
+            break;
+        }
+
+        if !result.is_empty() {
+            result.push_str(&separator);
+        }
+
+        result.push_str(word);
+    }
+
+    match result.is_empty() {
+        true => slug[..max_length].to_string(),
+        false => result
+    }
+}
+
+/// Create url slug that does not conflict with the column of the table, appending `-2`, `-3`, ... until it is unique,
+/// failing when `max_length` leaves no room for the base and the suffix.
+/// The check and the insert are separate queries, so concurrent requests can receive the same slug:
+/// keep a UNIQUE constraint on the column and call again when the insert fails with a unique violation
+///
+/// Example:
+/// ```
+/// use library::{databases, strings, DBPool};
+///
+/// fn main() {
+///     // Set database pool instance
+///     let result = databases::stage();
+///
+///     if result.is_ok() {
+///         let pool = DBPool::new(result.unwrap().clone());
+///
+///         // Retrieve unique slug for posts
+///         let slug = strings::unique_slug(&pool, "posts", "slug", "Hello World", 100);
+///     }
+/// }
+/// ```
+pub fn unique_slug<T: Into<String>>(pool: &DBPool, table: &str, column: &str, value: T, max_length: usize) -> Result<String, Errors> {
+    // Validate identifiers, e.g. `public.posts`
+    let (table, column) = match (databases::quote_identifier(table), databases::quote_identifier(column)) {
+        (Ok(table), Ok(column)) if !column.contains('.') => (table, column),
+        _ => return Err(Errors::new("Invalid table or column name"))
+    };
+
+    let slug = slugify_with(value, "-", max_length);
+    if slug.is_empty() {
+        return Err(Errors::new("Unable to create slug from an empty value"));
+    }
+
+    // Retrieve existing slugs with the same base, a zero length would mean no limit so keep at least a character
+    let base = |length: usize| slugify_with(&slug, "-", length.max(1));
+    let prefix = match max_length {
+        0 => slug.clone(),
+        _ => base(max_length.saturating_sub(11))
+    };
+    let query = format!(
+        "SELECT {column} AS slug FROM {table} WHERE {column} = $1 OR {column} LIKE $2",
+        column = column,
+        table = table
+    );
+
+    let mut conn = pool.get()?;
+    let rows: Vec<Slug> = diesel::sql_query(query)
+        .bind::<Text, _>(slug.clone())
+        .bind::<Text, _>(format!("{}%", prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")))
+        .load(&mut conn)
+        .map_err(|error| Errors::database("Unable to check slug uniqueness").with_source(error))?;
+
+    let existing: HashSet<String> = rows.into_iter().map(|row| row.slug).collect();
+    if !existing.contains(&slug) {
+        return Ok(slug);
+    }
+
+    // Append suffix, shortening the base so the slug stays within max length
+    let mut suffix = 2;
+    loop {
+        let suffix_bindings = format!("-{}", suffix);
+        if max_length != 0 && max_length <= suffix_bindings.len() {
+            return Err(Errors::new("Unable to create unique slug within the max length"));
+        }
+
+        let candidate = match max_length {
+            0 => format!("{}{}", slug, suffix_bindings),
+            _ => format!("{}{}", base(max_length.saturating_sub(suffix_bindings.len())), suffix_bindings)
+        };
+
+        if !existing.contains(&candidate) {
+            return Ok(candidate);
+        }
+
+        suffix += 1;
+    }
+}
+
+/// Struct container for slug rows of `unique_slug`
+#[derive(QueryableByName)]
+struct Slug {
+    #[diesel(sql_type = Text)]
+    slug: String,
+}
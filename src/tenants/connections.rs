@@ -0,0 +1,49 @@
+use diesel::RunQueryDsl;
+use std::ops::{Deref, DerefMut};
+
+use crate::Errors;
+use crate::PgPooledConnection;
+use crate::tenants::Tenant;
+
+/// Pooled connection with the tenant schema set as postgres `search_path`.
+/// The search path is reset once dropped so the connection goes back to the pool clean
+pub struct TenantConnection {
+    conn: PgPooledConnection,
+}
+
+/// TenantConnection implementation
+impl TenantConnection {
+    /// Set tenant search path on pooled connection
+    pub fn new(mut conn: PgPooledConnection, tenant: &Tenant) -> Result<Self, Errors> {
+        // Schema is validated by `Tenant::new`, quote it anyway
+        let query = format!("SET search_path TO \"{}\", public", tenant.schema.replace('"', ""));
+        if diesel::sql_query(query).execute(&mut conn).is_err() {
+            return Err(Errors::database("Unable to set tenant schema"));
+        }
+
+        Ok(Self { conn })
+    }
+}
+
+/// Deref implementation for TenantConnection
+impl Deref for TenantConnection {
+    type Target = PgPooledConnection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.conn
+    }
+}
+
+/// DerefMut implementation for TenantConnection
+impl DerefMut for TenantConnection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.conn
+    }
+}
+
+/// Reset search path before the connection returns to the pool
+impl Drop for TenantConnection {
+    fn drop(&mut self) {
+        let _ = diesel::sql_query("RESET search_path").execute(&mut self.conn);
+    }
+}
@@ -0,0 +1,83 @@
+use actix_web::{Error, HttpMessage};
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse};
+use actix_web::web::Data;
+use futures::future::{Either, ok, Ready};
+use handlebars::Handlebars;
+use std::task::{Context, Poll};
+use std::marker::PhantomData;
+
+use crate::audits::{Decision, Outcome};
+use crate::catchers;
+use crate::guards::AuthenticationFuture;
+use crate::Payload;
+use crate::problems;
+use crate::tenants::{Resolver, Tenant};
+
+/// TenancyMiddleware service struct
+pub struct TenancyMiddleware<S> {
+    pub service: S,
+    pub resolvers: Vec<Resolver>,
+    pub schema_prefix: String,
+    pub json_response: bool,
+    pub is_optional: bool,
+}
+
+/// Service implementation for TenancyMiddleware
+impl<S, B> Service<ServiceRequest> for TenancyMiddleware<S>
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        B: MessageBody,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = Either<AuthenticationFuture<S, B>, Ready<Result<Self::Response, Self::Error>>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        // Resolve tenant with the first resolver that returns a value
+        let tenant = self.resolvers
+            .iter()
+            .find_map(|resolver| resolver.resolve(req.request()))
+            .map(|id| Tenant::new(id, self.schema_prefix.clone()));
+
+        let payload = match tenant {
+            Some(Ok(tenant)) => {
+                req.extensions_mut().insert(tenant);
+
+                return Either::Left(AuthenticationFuture {
+                    fut: self.service.call(req),
+                    _phantom: PhantomData,
+                });
+            },
+            Some(Err(error)) => Payload::error(error.to_string()),
+            None if self.is_optional => {
+                return Either::Left(AuthenticationFuture {
+                    fut: self.service.call(req),
+                    _phantom: PhantomData,
+                });
+            },
+            None => Payload::localized(400, "unresolved-tenant"),
+        };
+
+        // Record denied access
+        Outcome::new(Decision::Denied, Some(payload.error.clone())).record(&req);
+
+        // Check response type
+        let hbs = req.app_data::<Data<Handlebars<'_>>>().cloned();
+        let response = match (self.json_response, hbs) {
+            (false, Some(hbs)) => catchers::not_found_middleware(hbs),
+            _ => problems::respond(req.request(), &payload),
+        };
+
+        // Disable access
+        Either::Right(ok(req
+            .into_response(response)
+            .map_into_boxed_body()
+            .map_into_right_body()))
+    }
+}
@@ -0,0 +1,11 @@
+pub mod connections;
+pub mod middlewares;
+pub mod resolvers;
+pub mod tenancies;
+pub mod tenants;
+
+pub use crate::tenants::connections::TenantConnection;
+pub use crate::tenants::middlewares::TenancyMiddleware;
+pub use crate::tenants::resolvers::Resolver;
+pub use crate::tenants::tenancies::Tenancy;
+pub use crate::tenants::tenants::{Tenant, MAX_SCHEMA_LENGTH};
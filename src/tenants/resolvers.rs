@@ -0,0 +1,84 @@
+use actix_web::HttpRequest;
+use actix_web::web::Data;
+use std::sync::{Arc, Mutex};
+
+use crate::Paseto;
+use crate::strings;
+
+/// Tenant resolution strategies, tried in the order they were registered
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resolver {
+    /// Left-most labels of the host before the base domain, e.g. `acme` for `acme.example.com`
+    Subdomain(String),
+    /// Value of a request header, e.g. `X-Tenant-Id`
+    Header(String),
+    /// Field of the access token claims (`data`), validated with the `Paseto` app data
+    Claim(String),
+}
+
+/// Resolver implementation
+impl Resolver {
+    /// Resolve tenant id from request
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::test::TestRequest;
+    /// use library::tenants::Resolver;
+    ///
+    /// fn main() {
+    ///     let req = TestRequest::default()
+    ///         .insert_header(("Host", "acme.example.com"))
+    ///         .to_http_request();
+    ///
+    ///     let tenant = Resolver::Subdomain(String::from("example.com")).resolve(&req);
+    ///     assert_eq!(tenant, Some(String::from("acme")));
+    /// }
+    /// ```
+    pub fn resolve(&self, req: &HttpRequest) -> Option<String> {
+        match self {
+            Resolver::Subdomain(base) => {
+                // Remove port from host
+                let host = req.connection_info().host().to_lowercase();
+                let host = host.split(':').next().unwrap_or_default().to_string();
+
+                // Retrieve labels before base domain
+                let suffix = format!(".{}", base.trim_start_matches('.').to_lowercase());
+                host.strip_suffix(&suffix)
+                    .filter(|item| !item.is_empty() && *item != "www")
+                    .map(|item| item.to_string())
+            },
+            Resolver::Header(name) => {
+                req.headers()
+                    .get(name.as_str())
+                    .and_then(|item| item.to_str().ok())
+                    .map(|item| item.trim().to_string())
+                    .filter(|item| !item.is_empty())
+            },
+            Resolver::Claim(key) => {
+                // Retrieve token
+                let authorization = req
+                    .headers()
+                    .get("Authorization")
+                    .map(|h| h.to_str().unwrap_or(""))
+                    .unwrap_or("")
+                    .trim();
+
+                let token = strings::get_token(authorization)?;
+
+                // Validate token and retrieve claim
+                let paseto = req.app_data::<Data<Arc<Mutex<Paseto>>>>()?;
+                let claims = paseto
+                    .lock()
+                    .ok()?
+                    .validate_access_token(token, serde_json::Value::Null)
+                    .ok()?;
+
+                match claims.get(key)? {
+                    serde_json::Value::String(value) => Some(value.clone()),
+                    serde_json::Value::Number(value) => Some(value.to_string()),
+                    _ => None
+                }
+            }
+        }
+    }
+}
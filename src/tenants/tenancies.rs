@@ -0,0 +1,118 @@
+use actix_web::Error;
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use futures::future::{ok, Ready};
+
+use crate::tenants::{Resolver, TenancyMiddleware};
+
+/// Tenancy struct middleware which resolves the request tenant and stores it in the request extensions
+pub struct Tenancy {
+    pub resolvers: Vec<Resolver>,
+    pub schema_prefix: String,
+    pub json_response: bool,
+    pub is_optional: bool,
+}
+
+/// Default implementation for Tenancy
+impl Default for Tenancy {
+    fn default() -> Self {
+        Self {
+            resolvers: vec![],
+            schema_prefix: String::from("tenant_"),
+            json_response: true,
+            is_optional: false,
+        }
+    }
+}
+
+/// Tenancy implementation
+impl Tenancy {
+    /// Creates Tenancy instance that tries each resolver in order
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::App;
+    /// use library::tenants::{Resolver, Tenancy};
+    ///
+    /// fn main() {
+    ///     App::new()
+    ///         .wrap(Tenancy::new(vec![
+    ///             Resolver::Header(String::from("X-Tenant-Id")),
+    ///             Resolver::Subdomain(String::from("example.com")),
+    ///         ]));
+    /// }
+    /// ```
+    pub fn new(resolvers: Vec<Resolver>) -> Self {
+        Self {
+            resolvers,
+            ..Self::default()
+        }
+    }
+
+    /// Creates Tenancy instance that resolves tenant from subdomain
+    pub fn subdomain<T: Into<String>>(base_domain: T) -> Self {
+        Self::new(vec![Resolver::Subdomain(base_domain.into())])
+    }
+
+    /// Creates Tenancy instance that resolves tenant from header
+    pub fn header<T: Into<String>>(name: T) -> Self {
+        Self::new(vec![Resolver::Header(name.into())])
+    }
+
+    /// Creates Tenancy instance that resolves tenant from access token claim
+    pub fn claim<T: Into<String>>(key: T) -> Self {
+        Self::new(vec![Resolver::Claim(key.into())])
+    }
+
+    /// Set prefix for tenant schema names
+    ///
+    /// Example
+    /// ```
+    /// use library::tenants::Tenancy;
+    ///
+    /// fn main() {
+    ///     let mut tenancy = Tenancy::header("X-Tenant-Id");
+    ///     tenancy.set_schema_prefix("org_");
+    /// }
+    /// ```
+    pub fn set_schema_prefix<T: Into<String>>(&mut self, prefix: T) -> &mut Self {
+        self.schema_prefix = prefix.into();
+        self
+    }
+
+    /// Let requests without tenant through
+    pub fn set_optional(&mut self) -> &mut Self {
+        self.is_optional = true;
+        self
+    }
+
+    /// Render the handlebars 404 page instead of json when no tenant is found
+    pub fn set_page_response(&mut self) -> &mut Self {
+        self.json_response = false;
+        self
+    }
+}
+
+/// Middleware factory is `Transform` trait
+impl<S, B> Transform<S, ServiceRequest> for Tenancy
+    where
+        S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+        S::Future: 'static,
+        B: MessageBody,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = TenancyMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(TenancyMiddleware {
+            service,
+            resolvers: self.resolvers.clone(),
+            schema_prefix: self.schema_prefix.clone(),
+            json_response: self.json_response,
+            is_optional: self.is_optional,
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::error::InternalError;
+use futures::future::{err, ok, Ready};
+use serde::{Serialize, Deserialize};
+
+use crate::Errors;
+use crate::Payload;
+
+/// Maximum length of postgres identifiers in bytes, longer ones are silently truncated
+pub const MAX_SCHEMA_LENGTH: usize = 63;
+
+/// Struct container for the tenant resolved for the current request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tenant {
+    pub id: String,
+    pub schema: String,
+}
+
+/// Tenant implementation
+impl Tenant {
+    /// Create new tenant whose postgres schema is the prefixed tenant id, kept as is so `acme-corp` and `acme_corp` never share a schema.
+    /// Only ascii letters, numbers, `-` and `_` are accepted so the schema is always a safe quoted identifier, e.g. `"tenant_acme-corp"`,
+    /// and schemas longer than `MAX_SCHEMA_LENGTH` are rejected instead of being truncated by postgres
+    ///
+    /// Example
+    /// ```
+    /// use library::tenants::Tenant;
+    ///
+    /// fn main() {
+    ///     let tenant = Tenant::new("acme-corp", "tenant_").unwrap();
+    ///     assert_eq!(tenant.schema, "tenant_acme-corp");
+    ///     assert_ne!(tenant.schema, Tenant::new("acme_corp", "tenant_").unwrap().schema);
+    ///     assert!(Tenant::new("acme; DROP SCHEMA public", "").is_err());
+    ///     assert!(Tenant::new("a".repeat(57), "tenant_").is_err());
+    /// }
+    /// ```
+    pub fn new<I, P>(id: I, schema_prefix: P) -> Result<Self, Errors>
+        where I: Into<String>,
+              P: Into<String>
+    {
+        // Create bindings
+        let id = id.into().trim().to_lowercase();
+        let prefix = schema_prefix.into();
+
+        // Validate identifiers
+        let is_valid = |value: &str| value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if id.is_empty() || !is_valid(&id) || !is_valid(&prefix) {
+            return Err(Errors::new("Invalid tenant identifier"));
+        }
+
+        // Set schema, postgres truncates longer identifiers so tenants sharing the first bytes would share a schema
+        let schema = format!("{}{}", prefix, id).to_lowercase();
+        if schema.len() > MAX_SCHEMA_LENGTH {
+            return Err(Errors::new("Tenant identifier is too long"));
+        }
+
+        Ok(Self { id, schema })
+    }
+}
+
+/// Implement from request
+impl FromRequest for Tenant {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut DevPayload) -> Self::Future {
+        match req.extensions().get::<Tenant>() {
+            Some(tenant) => ok(tenant.clone()),
+            None => {
+                err(InternalError::from_response(
+                    Errors::new("Unable to resolve tenant"),
+                    Payload::localized(400, "unresolved-tenant").to_response()
+                ).into())
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod storages;
+pub mod uploaders;
+pub mod uploads;
+
+pub use crate::uploads::storages::{Disk, DiskWriter, Storage, Writer};
+pub use crate::uploads::uploaders::{Rule, Uploader};
+pub use crate::uploads::uploads::Uploads;
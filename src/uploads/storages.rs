@@ -0,0 +1,136 @@
+use actix_web::web::{self, Bytes};
+use futures::future::LocalBoxFuture;
+use std::fs::{self, File as StdFile};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::Errors;
+
+/// Storage that uploads are streamed to while the multipart parts arrive
+pub trait Storage {
+    /// Create writer for the key with the detected mime type
+    fn create(&self, key: &str, mime: &str) -> LocalBoxFuture<'static, Result<Box<dyn Writer>, Errors>>;
+
+    /// Remove stored key, used to clean up uploads of a rejected request
+    fn remove(&self, key: &str) -> LocalBoxFuture<'static, Result<(), Errors>>;
+}
+
+/// Writer of a single upload
+pub trait Writer {
+    /// Write chunk of the upload
+    fn write(&mut self, chunk: Bytes) -> LocalBoxFuture<'_, Result<(), Errors>>;
+
+    /// Complete the upload
+    fn finish(self: Box<Self>) -> LocalBoxFuture<'static, Result<(), Errors>>;
+
+    /// Discard the upload
+    fn abort(self: Box<Self>) -> LocalBoxFuture<'static, ()>;
+}
+
+/// Struct container for disk storage, keys are stored inside the directory
+///
+/// Example
+/// ```
+/// use library::uploads::{Disk, Storage};
+///
+/// #[actix_web::main]
+/// async fn main() {
+///     let disk = Disk::new(std::env::temp_dir().join("library-uploads"));
+///
+///     let mut writer = disk.create("hello.txt", "text/plain").await.unwrap();
+///     writer.write("hello".into()).await.unwrap();
+///     writer.finish().await.unwrap();
+///
+///     assert_eq!(std::fs::read_to_string(disk.directory.join("hello.txt")).unwrap(), "hello");
+///     disk.remove("hello.txt").await.unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Disk {
+    pub directory: PathBuf,
+}
+
+/// Disk implementation
+impl Disk {
+    /// Create disk storage for the directory
+    pub fn new<T: Into<PathBuf>>(directory: T) -> Self {
+        Self { directory: directory.into() }
+    }
+}
+
+/// Storage implementation for Disk, file io runs on the blocking thread pool so workers keep serving requests
+impl Storage for Disk {
+    fn create(&self, key: &str, _mime: &str) -> LocalBoxFuture<'static, Result<Box<dyn Writer>, Errors>> {
+        let path = self.directory.join(key);
+
+        Box::pin(async move {
+            let (file, path) = blocking(move || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).map_err(|error| Errors::config("Unable to create upload directory").with_source(error))?;
+                }
+
+                let file = StdFile::create(&path).map_err(|error| Errors::config("Unable to create upload file").with_source(error))?;
+                Ok((file, path))
+            }).await?;
+
+            Ok(Box::new(DiskWriter { file: Some(file), path }) as Box<dyn Writer>)
+        })
+    }
+
+    fn remove(&self, key: &str) -> LocalBoxFuture<'static, Result<(), Errors>> {
+        let path = self.directory.join(key);
+
+        Box::pin(async move {
+            blocking(move || fs::remove_file(path).map_err(|error| Errors::config("Unable to remove upload file").with_source(error))).await
+        })
+    }
+}
+
+/// Struct container for a file being written to disk
+pub struct DiskWriter {
+    file: Option<StdFile>,
+    path: PathBuf,
+}
+
+/// Writer implementation for DiskWriter
+impl Writer for DiskWriter {
+    fn write(&mut self, chunk: Bytes) -> LocalBoxFuture<'_, Result<(), Errors>> {
+        Box::pin(async move {
+            // Move the file to the blocking thread pool and take it back once written
+            let mut file = self.file.take().ok_or_else(|| Errors::config("Upload file is closed").logged())?;
+            let file = blocking(move || {
+                file.write_all(&chunk).map_err(|error| Errors::config("Unable to write upload file").with_source(error))?;
+                Ok(file)
+            }).await?;
+
+            self.file = Some(file);
+            Ok(())
+        })
+    }
+
+    fn finish(mut self: Box<Self>) -> LocalBoxFuture<'static, Result<(), Errors>> {
+        Box::pin(async move {
+            let mut file = self.file.take().ok_or_else(|| Errors::config("Upload file is closed").logged())?;
+            blocking(move || file.flush().map_err(|error| Errors::config("Unable to write upload file").with_source(error))).await
+        })
+    }
+
+    fn abort(mut self: Box<Self>) -> LocalBoxFuture<'static, ()> {
+        Box::pin(async move {
+            drop(self.file.take());
+            let path = self.path;
+            let _ = web::block(move || fs::remove_file(path)).await;
+        })
+    }
+}
+
+// Run file io on the blocking thread pool, logging failures
+async fn blocking<T, F>(callback: F) -> Result<T, Errors>
+    where T: Send + 'static,
+          F: FnOnce() -> Result<T, Errors> + Send + 'static
+{
+    match web::block(callback).await {
+        Ok(result) => result.map_err(Errors::logged),
+        Err(error) => Err(Errors::config("Unable to run upload file task").with_source(error).logged())
+    }
+}
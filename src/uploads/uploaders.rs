@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::mimes;
+use crate::uploads::Storage;
+
+/// Types browsers run scripts in, only allowed when listed explicitly and never matched by wildcards like `image/*`
+pub const ACTIVE: &[&str] = &["image/svg+xml", "text/html", "application/xhtml+xml", "application/vnd.mozilla.xul+xml"];
+
+/// Struct container for the upload rules of a file field
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Rule {
+    pub limit: usize,
+    pub mimes: Vec<String>,
+    pub is_extension_trusted: bool,
+}
+
+/// Rule implementation
+impl Rule {
+    /// Check if the detected mime type is allowed, e.g. `image/png` matches `image/*` and aliases like `image/jpg` match `image/jpeg`.
+    /// An empty allowlist allows any type except the `ACTIVE` ones, which must be listed explicitly
+    ///
+    /// Example
+    /// ```
+    /// use library::uploads::Rule;
+    ///
+    /// fn main() {
+    ///     let rule = Rule { limit: 1024, mimes: vec![String::from("image/*"), String::from("application/pdf")], ..Rule::default() };
+    ///
+    ///     assert!(rule.is_allowed("image/png"));
+    ///     assert!(rule.is_allowed("application/pdf"));
+    ///     assert!(!rule.is_allowed("application/zip"));
+    ///     assert!(!rule.is_allowed("image/svg+xml"));
+    ///
+    ///     let rule = Rule { limit: 1024, mimes: vec![String::from("image/jpg"), String::from("image/svg+xml")], ..Rule::default() };
+    ///     assert!(rule.is_allowed("image/jpeg"));
+    ///     assert!(rule.is_allowed("image/svg+xml"));
+    /// }
+    /// ```
+    pub fn is_allowed(&self, mime: &str) -> bool {
+        let mime = mimes::canonical(mime);
+
+        let is_active = ACTIVE.contains(&mime.as_str());
+
+        (self.mimes.is_empty() && !is_active) || self.mimes.iter().any(|item| match item.strip_suffix("/*") {
+            Some(prefix) => !is_active && mime.starts_with(&format!("{}/", prefix.to_lowercase())),
+            None => mimes::canonical(item) == mime
+        })
+    }
+}
+
+/// Struct container for the `Uploads` extractor config, register it with `app_data`
+///
+/// Example
+/// ```
+/// use actix_web::App;
+/// use actix_web::web::Data;
+/// use library::uploads::{Disk, Uploader};
+///
+/// fn main() {
+///     let mut uploader = Uploader::new(Disk::new("/tmp/uploads"));
+///     uploader
+///         .set_field("avatar", 2_097_152, vec!["image/png", "image/jpeg"])
+///         .set_field("resume", 10_485_760, vec!["application/pdf"]);
+///
+///     App::new().app_data(Data::new(uploader));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Uploader {
+    pub storage: Arc<dyn Storage>,
+    pub rules: HashMap<String, Rule>,
+    pub text_limit: usize,
+    pub text_fields_limit: usize,
+    pub text_total_limit: usize,
+    pub prefix: String,
+}
+
+/// Uploader implementation
+impl Uploader {
+    /// Create uploader streaming to the storage, text fields are limited to 64KB each, 32 fields and 1MB in total
+    pub fn new<S: Storage + 'static>(storage: S) -> Self {
+        Self {
+            storage: Arc::new(storage),
+            rules: HashMap::new(),
+            text_limit: 65_536,
+            text_fields_limit: 32,
+            text_total_limit: 1_048_576,
+            prefix: String::default(),
+        }
+    }
+
+    /// Set size limit in bytes and allowed mime types of a file field, file fields without rules are rejected
+    pub fn set_field<N, M>(&mut self, name: N, limit: usize, mimes: Vec<M>) -> &mut Self
+        where N: Into<String>,
+              M: Into<String>
+    {
+        self.rules.insert(name.into(), Rule { limit, mimes: mimes.into_iter().map(Into::into).collect(), is_extension_trusted: false });
+        self
+    }
+
+    /// Set if the file name extension may decide the type of a file field when its bytes have no signature (csv, txt, tga).
+    /// Off by default since the client picks the file name, only enable it for fields served as attachments
+    pub fn set_extension_trusted(&mut self, name: &str, is_extension_trusted: bool) -> &mut Self {
+        if let Some(rule) = self.rules.get_mut(name) {
+            rule.is_extension_trusted = is_extension_trusted;
+        }
+
+        self
+    }
+
+    /// Set size limit in bytes of each text field
+    pub fn set_text_limit(&mut self, text_limit: usize) -> &mut Self {
+        self.text_limit = text_limit;
+        self
+    }
+
+    /// Set maximum number of text fields
+    pub fn set_text_fields_limit(&mut self, text_fields_limit: usize) -> &mut Self {
+        self.text_fields_limit = text_fields_limit;
+        self
+    }
+
+    /// Set size limit in bytes of all text fields together
+    pub fn set_text_total_limit(&mut self, text_total_limit: usize) -> &mut Self {
+        self.text_total_limit = text_total_limit;
+        self
+    }
+
+    /// Set prefix of the stored keys, e.g. `avatars/`
+    pub fn set_prefix<T: Into<String>>(&mut self, prefix: T) -> &mut Self {
+        self.prefix = prefix.into();
+        self
+    }
+}
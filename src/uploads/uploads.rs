@@ -0,0 +1,279 @@
+use actix_multipart::{Field, Multipart, MultipartError};
+use actix_web::{Error, FromRequest, HttpRequest};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::web::{Bytes, Data};
+use futures::StreamExt;
+use futures::future::LocalBoxFuture;
+use image::io::Reader as ImageReader;
+use rand::Rng;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Cursor;
+
+use crate::{Errors, File};
+use crate::enums::EnumI32;
+use crate::locales;
+use crate::mimes;
+use crate::stages;
+use crate::strings;
+use crate::uploads::{Rule, Uploader, Writer};
+
+/// Bytes buffered before the mime type is detected and the upload starts
+const SNIFF_SIZE: usize = 8192;
+
+/// Bytes kept from the start of images to read their dimensions
+const PROBE_SIZE: usize = 65_536;
+
+/// Extractor that streams multipart file fields to the `Uploader` storage as they arrive, yielding `File` placeholders
+///
+/// Example
+/// ```
+/// use actix_web::{web, App, HttpResponse};
+/// use actix_web::web::Data;
+/// use library::uploads::{Disk, Uploader, Uploads};
+///
+/// async fn upload(uploads: Uploads) -> HttpResponse {
+///     HttpResponse::Ok().json(uploads.file("avatar"))
+/// }
+///
+/// fn main() {
+///     let mut uploader = Uploader::new(Disk::new("/tmp/uploads"));
+///     uploader.set_field("avatar", 2_097_152, vec!["image/*"]);
+///
+///     App::new()
+///         .app_data(Data::new(uploader))
+///         .route("/upload", web::post().to(upload));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Uploads {
+    pub files: BTreeMap<String, Vec<File>>,
+    pub fields: HashMap<String, String>,
+}
+
+/// Uploads implementation
+impl Uploads {
+    /// Retrieve first file of the field
+    pub fn file(&self, name: &str) -> Option<&File> {
+        self.files.get(name).and_then(|files| files.first())
+    }
+
+    /// Retrieve text field value
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+
+    /// Retrieve stored keys of all files
+    pub fn keys(&self) -> Vec<String> {
+        self.files.values()
+            .flatten()
+            .filter_map(|file| file.file_name.clone())
+            .collect()
+    }
+}
+
+/// FromRequest implementation for Uploads
+impl FromRequest for Uploads {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut DevPayload) -> Self::Future {
+        let req = req.clone();
+        let mut multipart = Multipart::new(req.headers(), payload.take());
+
+        Box::pin(async move {
+            let uploader = match req.app_data::<Data<Uploader>>() {
+                Some(uploader) => uploader.clone(),
+                None => {
+                    let message = locales::translate(&req, "invalid-configuration", &[]);
+                    return Err(stages::respond(&req, Errors::config("Uploader is not registered"), 500, message, None));
+                }
+            };
+
+            let mut uploads = Uploads::default();
+            while let Some(field) = multipart.next().await {
+                let result = match field {
+                    Ok(field) => read(&req, &uploader, &mut uploads, field).await,
+                    Err(error) => {
+                        let message = locales::translate(&req, "multipart-error", &[("error", &error.to_string())]);
+                        Err(stages::respond(&req, error, 400, message, None))
+                    }
+                };
+
+                // Remove files stored before the failing part
+                if let Err(error) = result {
+                    for key in uploads.keys() {
+                        let _ = uploader.storage.remove(&key).await;
+                    }
+
+                    return Err(error);
+                }
+            }
+
+            Ok(uploads)
+        })
+    }
+}
+
+// Read multipart field into uploads
+async fn read(req: &HttpRequest, uploader: &Uploader, uploads: &mut Uploads, mut field: Field) -> Result<(), Error> {
+    let name = field.name().unwrap_or_default().to_string();
+    let label = field.content_disposition().and_then(|item| item.get_filename()).map(String::from);
+
+    // Text fields are buffered up to the text, field count and total limits
+    let label = match label {
+        Some(label) => label,
+        None => {
+            if uploads.fields.len() >= uploader.text_fields_limit && !uploads.fields.contains_key(&name) {
+                let message = locales::translate(req, "upload-fields", &[("limit", &uploader.text_fields_limit.to_string())]);
+                return Err(stages::respond(req, Errors::new(message.clone()), 413, message, None));
+            }
+
+            let total: usize = uploads.fields.iter().filter(|(key, _)| **key != name).map(|(_, value)| value.len()).sum();
+            let mut value = Vec::new();
+            while let Some(chunk) = field.next().await {
+                let chunk = chunk.map_err(|error| multipart_error(req, &name, error))?;
+                if value.len() + chunk.len() > uploader.text_limit {
+                    return Err(too_large(req, &name));
+                }
+
+                if total + value.len() + chunk.len() > uploader.text_total_limit {
+                    let message = locales::translate(req, "upload-fields-too-large", &[]);
+                    return Err(stages::respond(req, Errors::new(message.clone()), 413, message, None));
+                }
+
+                value.extend_from_slice(&chunk);
+            }
+
+            uploads.fields.insert(name, String::from_utf8_lossy(&value).to_string());
+            return Ok(());
+        }
+    };
+
+    let rule = match uploader.rules.get(&name) {
+        Some(rule) => rule.clone(),
+        None => {
+            let message = locales::translate(req, "multipart-unknown-field", &[]);
+            return Err(stages::respond(req, MultipartError::UnknownField(name.clone()), 400, message, Some(name)));
+        }
+    };
+
+    let mut upload = Upload { label, head: Vec::new(), probe: Vec::new(), size: 0, mime: String::default(), key: String::default(), writer: None };
+    let result = stream(req, uploader, &rule, &name, &mut upload, &mut field).await;
+    let writer = upload.writer.take();
+
+    match (result, writer) {
+        (Ok(_), Some(writer)) => writer.finish().await.map_err(|error| upload_error(req, &name, error))?,
+        (Err(error), Some(writer)) => {
+            writer.abort().await;
+            return Err(error);
+        },
+        (result, None) => result?
+    }
+
+    // Populate file placeholder
+    let mut file = File::new();
+    file.file_name = Some(upload.key);
+    file.file_size = Some(strings::get_file_size(upload.size as f64));
+    file.label = Some(upload.label);
+    if File::is_image(&upload.mime) {
+        if let Ok((width, height)) = ImageReader::new(Cursor::new(upload.probe)).with_guessed_format().map_err(image::ImageError::IoError).and_then(|reader| reader.into_dimensions()) {
+            file.width = Some(EnumI32::I32(width as i32));
+            file.height = Some(EnumI32::I32(height as i32));
+        }
+    }
+
+    file.mime_type = Some(upload.mime);
+    uploads.files.entry(name).or_default().push(file);
+
+    Ok(())
+}
+
+/// Struct container for the state of a streaming upload
+struct Upload {
+    label: String,
+    head: Vec<u8>,
+    probe: Vec<u8>,
+    size: usize,
+    mime: String,
+    key: String,
+    writer: Option<Box<dyn Writer>>,
+}
+
+// Stream field chunks into the storage, starting the upload once the mime type is detected
+async fn stream(req: &HttpRequest, uploader: &Uploader, rule: &Rule, name: &str, upload: &mut Upload, field: &mut Field) -> Result<(), Error> {
+    while let Some(chunk) = field.next().await {
+        let chunk = chunk.map_err(|error| multipart_error(req, name, error))?;
+        upload.size += chunk.len();
+        if upload.size > rule.limit {
+            return Err(too_large(req, name));
+        }
+
+        if upload.probe.len() < PROBE_SIZE {
+            let length = chunk.len().min(PROBE_SIZE - upload.probe.len());
+            upload.probe.extend_from_slice(&chunk[..length]);
+        }
+
+        match upload.writer.as_mut() {
+            Some(writer) => writer.write(chunk).await.map_err(|error| upload_error(req, name, error))?,
+            None => {
+                upload.head.extend_from_slice(&chunk);
+                if upload.head.len() >= SNIFF_SIZE {
+                    start(req, uploader, rule, name, upload).await?;
+                }
+            }
+        }
+    }
+
+    if upload.writer.is_none() {
+        start(req, uploader, rule, name, upload).await?;
+    }
+
+    Ok(())
+}
+
+// Detect mime type from the magic bytes (or the file name extension when the field trusts it), check the allowlist and write the buffered head
+async fn start(req: &HttpRequest, uploader: &Uploader, rule: &Rule, name: &str, upload: &mut Upload) -> Result<(), Error> {
+    upload.mime = match rule.is_extension_trusted {
+        true => mimes::detect(&upload.head, Some(&upload.label)),
+        false => mimes::sniff(&upload.head, Some(&upload.label))
+    };
+
+    if !rule.is_allowed(&upload.mime) {
+        let message = locales::translate(req, "upload-mime", &[("mime", &upload.mime)]);
+        return Err(stages::respond(req, Errors::validation(message.clone(), vec![(name, message.clone())]), 415, message, Some(name.to_string())));
+    }
+
+    let random: [u8; 16] = rand::thread_rng().gen();
+    let random: String = random.iter().map(|byte| format!("{:02x}", byte)).collect();
+    upload.key = format!("{}{}{}", uploader.prefix, random, strings::get_extension_from_mime(&upload.mime));
+
+    let mut writer = uploader.storage.create(&upload.key, &upload.mime).await.map_err(|error| upload_error(req, name, error))?;
+    let head = Bytes::from(std::mem::take(&mut upload.head));
+    if !head.is_empty() {
+        if let Err(error) = writer.write(head).await {
+            writer.abort().await;
+            return Err(upload_error(req, name, error));
+        }
+    }
+
+    upload.writer = Some(writer);
+    Ok(())
+}
+
+// Create response for fields bigger than allowed
+fn too_large(req: &HttpRequest, name: &str) -> Error {
+    let message = locales::translate(req, "upload-too-large", &[]);
+    stages::respond(req, Errors::validation(message.clone(), vec![(name, message.clone())]), 413, message, Some(name.to_string()))
+}
+
+// Create response for multipart stream failures
+fn multipart_error(req: &HttpRequest, name: &str, error: MultipartError) -> Error {
+    let message = locales::translate(req, "multipart-error", &[("error", &error.to_string())]);
+    stages::respond(req, error, 400, message, Some(name.to_string()))
+}
+
+// Create response for storage failures
+fn upload_error(req: &HttpRequest, name: &str, error: Errors) -> Error {
+    let message = locales::translate(req, "upload-error", &[]);
+    stages::respond(req, error, 502, message, Some(name.to_string()))
+}
@@ -11,7 +11,7 @@ use serde::Serialize;
 use user_agent_parser::UserAgentParser as UAParser;
 
 use user_agent_cpu::UserAgentCPU;
-use user_agent_device::UserAgentDevice;
+pub use user_agent_device::UserAgentDevice;
 use user_agent_engine::UserAgentEngine;
 use user_agent_os::UserAgentOS;
 use user_agent_product::UserAgentProduct;
@@ -80,10 +80,7 @@ impl<S, B> Service<ServiceRequest> for UserAgentParserMiddleware<S>
             .unwrap_or("");
 
         // Retrieve request
-        let ip = req
-            .connection_info()
-            .realip_remote_addr()
-            .map_or(String::default(), |item| item.to_string());
+        let ip = get_ip(req.request());
 
         // Retrieve parser
         let parser = req.app_data::<Data<UAParser>>()
@@ -98,6 +95,28 @@ impl<S, B> Service<ServiceRequest> for UserAgentParserMiddleware<S>
     }
 }
 
+/// Retrieve client ip from connection info (honors `Forwarded` and `X-Forwarded-For` headers).
+/// The headers are client controlled, so use it for display and audits only, access checks use `Firewall::client_ip`
+///
+/// Example
+/// ```
+/// use actix_web::test::TestRequest;
+/// use library::user_agent;
+///
+/// fn main() {
+///     let req = TestRequest::default()
+///         .insert_header(("X-Forwarded-For", "203.0.113.7"))
+///         .to_http_request();
+///
+///     let ip = user_agent::get_ip(&req);
+/// }
+/// ```
+pub fn get_ip(req: &HttpRequest) -> String {
+    req
+        .connection_info()
+        .realip_remote_addr()
+        .map_or(String::default(), |item| item.to_string())
+}
 
 // Create user agent object
 #[derive(Debug, Clone, Serialize)]
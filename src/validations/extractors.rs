@@ -0,0 +1,169 @@
+use actix_web::{Error, FromRequest, HttpRequest};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::web::{Form, Json};
+use futures::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::ops::{Deref, DerefMut};
+use validator::Validate;
+
+use crate::stages;
+use crate::validations;
+
+/// Declares a validated extractor, `$read` deserializes the request into `T` before it is validated
+macro_rules! validated {
+    ($(#[$meta:meta])* $name:ident, $read:ident) => {
+        $(#[$meta])*
+        pub struct $name<T>(pub T);
+
+        #[doc = concat!(stringify!($name), " implementation")]
+        impl<T> $name<T> {
+            /// Unwrap into inner value
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        #[doc = concat!("Deref implementation for ", stringify!($name))]
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #[doc = concat!("DerefMut implementation for ", stringify!($name))]
+        impl<T> DerefMut for $name<T> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        #[doc = concat!("FromRequest implementation for ", stringify!($name))]
+        impl<T: DeserializeOwned + Validate + 'static> FromRequest for $name<T> {
+            type Error = Error;
+            type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+            fn from_request(req: &HttpRequest, payload: &mut DevPayload) -> Self::Future {
+                let req = req.clone();
+                let item = $read::<T>(&req, payload);
+
+                Box::pin(async move {
+                    let item = item.await?;
+                    validations::validate(&req, &item)?;
+
+                    Ok(Self(item))
+                })
+            }
+        }
+    };
+}
+
+validated!(
+    /// Extractor that reads the json body with actix's `Json` (honoring its config), deserializes it reporting the failing field path, then validates it.
+    /// Use `ValidatedJson<Sanitized<T>>` to sanitize the item before it is validated
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::{App, web};
+    /// use library::validations::ValidatedJson;
+    /// use serde::Deserialize;
+    /// use validator::Validate;
+    ///
+    /// #[derive(Deserialize, Validate)]
+    /// pub struct Signup {
+    ///     #[validate(email(message = "Email is invalid"))]
+    ///     pub email: String,
+    /// }
+    ///
+    /// async fn handler(item: ValidatedJson<Signup>) -> String {
+    ///     item.email.clone()
+    /// }
+    ///
+    /// fn main() {
+    ///     App::new().route("/signup", web::post().to(handler));
+    /// }
+    /// ```
+    ValidatedJson, json
+);
+
+validated!(
+    /// Extractor that reads the url encoded form body with actix's `Form` (honoring its config), deserializes it reporting the failing field, then validates it
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::{App, web};
+    /// use library::validations::ValidatedForm;
+    /// use serde::Deserialize;
+    /// use validator::Validate;
+    ///
+    /// #[derive(Deserialize, Validate)]
+    /// pub struct Signup {
+    ///     #[validate(email(message = "Email is invalid"))]
+    ///     pub email: String,
+    /// }
+    ///
+    /// async fn handler(item: ValidatedForm<Signup>) -> String {
+    ///     item.email.clone()
+    /// }
+    ///
+    /// fn main() {
+    ///     App::new().route("/signup", web::post().to(handler));
+    /// }
+    /// ```
+    ValidatedForm, form
+);
+
+validated!(
+    /// Extractor that deserializes the query string reporting the failing field, then validates it
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::{App, web};
+    /// use library::validations::ValidatedQuery;
+    /// use serde::Deserialize;
+    /// use validator::Validate;
+    ///
+    /// #[derive(Deserialize, Validate)]
+    /// pub struct Search {
+    ///     #[validate(length(min = 3, message = "Keyword is too short"))]
+    ///     pub keyword: String,
+    /// }
+    ///
+    /// async fn handler(item: ValidatedQuery<Search>) -> String {
+    ///     item.keyword.clone()
+    /// }
+    ///
+    /// fn main() {
+    ///     App::new().route("/search", web::get().to(handler));
+    /// }
+    /// ```
+    ValidatedQuery, query
+);
+
+// Read json body with actix's `Json`, then deserialize it tracking the field path
+fn json<T: DeserializeOwned + 'static>(req: &HttpRequest, payload: &mut DevPayload) -> LocalBoxFuture<'static, Result<T, Error>> {
+    let req = req.clone();
+    let value = Json::<Value>::from_request(&req, payload);
+
+    Box::pin(async move { stages::deserialize(&req, value.await?.into_inner()) })
+}
+
+// Read url encoded body with actix's `Form`, then deserialize it tracking the field
+fn form<T: DeserializeOwned + 'static>(req: &HttpRequest, payload: &mut DevPayload) -> LocalBoxFuture<'static, Result<T, Error>> {
+    let req = req.clone();
+    let pairs = Form::<Vec<(String, String)>>::from_request(&req, payload);
+
+    Box::pin(async move {
+        let body = serde_urlencoded::to_string(pairs.await?.into_inner()).unwrap_or_default();
+        stages::deserialize_form(&req, body.as_bytes())
+    })
+}
+
+// Deserialize query string tracking the field
+fn query<T: DeserializeOwned + 'static>(req: &HttpRequest, _: &mut DevPayload) -> LocalBoxFuture<'static, Result<T, Error>> {
+    let result = stages::deserialize_query(req);
+
+    Box::pin(async move { result })
+}
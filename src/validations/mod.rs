@@ -0,0 +1,85 @@
+pub mod extractors;
+pub mod sanitizers;
+
+use actix_web::HttpRequest;
+use actix_web::error::InternalError;
+use std::collections::BTreeMap;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+pub use crate::validations::extractors::{ValidatedForm, ValidatedJson, ValidatedQuery};
+pub use crate::validations::sanitizers::Sanitized;
+
+use crate::{Errors, Payload};
+use crate::locales;
+use crate::problems;
+
+/// Validate item, failures respond 422 with messages per field. Wrap the item in `Sanitized` to sanitize it first
+pub fn validate<T: Validate>(req: &HttpRequest, item: &T) -> Result<(), actix_web::Error> {
+    // Validate item
+    let errors = match item.validate() {
+        Ok(_) => return Ok(()),
+        Err(errors) => errors
+    };
+
+    let message = locales::translate(req, "validation-failed", &[]);
+    let fields = fields(&errors);
+
+    let mut payload = Payload::new(422);
+    payload.error = message.clone();
+    payload.errors = serde_json::to_value(&fields).unwrap_or_default();
+
+    Err(InternalError::from_response(
+        Errors::Validation(message, fields),
+        problems::respond(req, &payload)
+    ).into())
+}
+
+/// Flatten validation errors into messages per field path, e.g. `address.city` or `items[2].price`
+///
+/// Example
+/// ```
+/// use library::validations;
+/// use validator::Validate;
+///
+/// #[derive(Validate)]
+/// pub struct Signup {
+///     #[validate(email(message = "Email is invalid"))]
+///     pub email: String,
+/// }
+///
+/// fn main() {
+///     let errors = Signup { email: String::from("john") }.validate().unwrap_err();
+///     assert_eq!(validations::fields(&errors)["email"], vec!["Email is invalid"]);
+/// }
+/// ```
+pub fn fields(errors: &ValidationErrors) -> BTreeMap<String, Vec<String>> {
+    let mut fields = BTreeMap::new();
+    collect(&mut fields, "", errors);
+
+    fields
+}
+
+// Collect messages of nested validation errors
+fn collect(fields: &mut BTreeMap<String, Vec<String>>, prefix: &str, errors: &ValidationErrors) {
+    for (field, kind) in errors.errors() {
+        let path = match prefix.is_empty() {
+            true => field.to_string(),
+            false => format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                let messages = fields.entry(path).or_default();
+                for error in errors {
+                    messages.push(error.message.as_ref().map(|item| item.to_string()).unwrap_or_else(|| error.code.to_string()));
+                }
+            },
+            ValidationErrorsKind::Struct(errors) => collect(fields, &path, errors),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect(fields, &format!("{}[{}]", path, index), errors);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use sanitizer::prelude::Sanitize;
+use serde::{Deserialize, Deserializer};
+use std::ops::{Deref, DerefMut};
+use validator::{Validate, ValidationErrors};
+
+/// Struct container for an item sanitized right after it is deserialized, opting validated extractors into sanitizing,
+/// e.g. `ValidatedJson<Sanitized<Signup>>`
+///
+/// Example
+/// ```
+/// use library::validations::Sanitized;
+/// use sanitizer::prelude::Sanitize;
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// pub struct Signup {
+///     #[validate(email(message = "Email is invalid"))]
+///     pub email: String,
+/// }
+///
+/// impl Sanitize for Signup {
+///     fn sanitize(&mut self) {
+///         self.email = self.email.trim().to_lowercase();
+///     }
+/// }
+///
+/// fn main() {
+///     let signup: Sanitized<Signup> = serde_json::from_str(r#"{"email": " John@Example.com "}"#).unwrap();
+///     assert_eq!(signup.email, "john@example.com");
+///     assert!(signup.validate().is_ok());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sanitized<T>(pub T);
+
+/// Sanitized implementation
+impl<T> Sanitized<T> {
+    /// Unwrap into inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Deref implementation for Sanitized
+impl<T> Deref for Sanitized<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// DerefMut implementation for Sanitized
+impl<T> DerefMut for Sanitized<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Deserialize implementation for Sanitized, sanitizes the deserialized item
+impl<'de, T: Deserialize<'de> + Sanitize> Deserialize<'de> for Sanitized<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut item = T::deserialize(deserializer)?;
+        item.sanitize();
+
+        Ok(Self(item))
+    }
+}
+
+/// Validate implementation for Sanitized, validates the sanitized item
+impl<T: Validate> Validate for Sanitized<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.0.validate()
+    }
+}
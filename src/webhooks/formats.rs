@@ -0,0 +1,106 @@
+use actix_web::HttpRequest;
+
+use crate::Errors;
+use crate::webhooks::Signature;
+
+/// Signature header formats supported by the webhook extractor
+#[derive(Clone)]
+pub enum Format {
+    /// `X-Hub-Signature-256: sha256=<hex>` signed over the raw body
+    Github,
+    /// `X-Slack-Signature: v0=<hex>` signed over `v0:<X-Slack-Request-Timestamp>:<body>`
+    Slack,
+    /// `Stripe-Signature: t=<timestamp>,v1=<hex>` signed over `<timestamp>.<body>`
+    Stripe,
+    /// Custom parser for providers with their own header layout
+    Custom(fn(&HttpRequest, &[u8]) -> Result<Signature, Errors>),
+}
+
+/// Format implementation
+impl Format {
+    /// Extract signatures, signed message and timestamp from request
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::test::TestRequest;
+    /// use library::webhooks::Format;
+    ///
+    /// fn main() {
+    ///     let req = TestRequest::post()
+    ///         .insert_header(("X-Hub-Signature-256", "sha256=0a1b"))
+    ///         .to_http_request();
+    ///
+    ///     let signature = Format::Github.extract(&req, b"{}");
+    /// }
+    /// ```
+    pub fn extract(&self, req: &HttpRequest, body: &[u8]) -> Result<Signature, Errors> {
+        match self {
+            Format::Github => {
+                let header = get_header(req, "X-Hub-Signature-256")?;
+                let signature = header.strip_prefix("sha256=").unwrap_or(&header);
+
+                let mut result = Signature::new();
+                result.signatures.push(decode_hex(signature)?);
+                result.message = body.to_vec();
+
+                Ok(result)
+            },
+            Format::Slack => {
+                let header = get_header(req, "X-Slack-Signature")?;
+                let timestamp = get_header(req, "X-Slack-Request-Timestamp")?;
+                let signature = header.strip_prefix("v0=").unwrap_or(&header);
+
+                let mut result = Signature::new();
+                result.signatures.push(decode_hex(signature)?);
+                result.timestamp = Some(parse_timestamp(&timestamp)?);
+                result.message = [format!("v0:{}:", timestamp).as_bytes(), body].concat();
+
+                Ok(result)
+            },
+            Format::Stripe => {
+                let header = get_header(req, "Stripe-Signature")?;
+
+                // Split `t=...,v1=...,v1=...` pairs
+                let mut result = Signature::new();
+                let mut timestamp = String::default();
+                for (key, value) in header.split(',').filter_map(|item| item.trim().split_once('=')) {
+                    match key {
+                        "t" => timestamp = value.to_string(),
+                        "v1" => result.signatures.push(decode_hex(value)?),
+                        _ => {}
+                    }
+                }
+
+                if result.signatures.is_empty() {
+                    return Err(Errors::new("Missing webhook signature"));
+                }
+
+                result.timestamp = Some(parse_timestamp(&timestamp)?);
+                result.message = [format!("{}.", timestamp).as_bytes(), body].concat();
+
+                Ok(result)
+            },
+            Format::Custom(callback) => (callback)(req, body),
+        }
+    }
+}
+
+/// Retrieve header value as trimmed string
+pub fn get_header(req: &HttpRequest, name: &str) -> Result<String, Errors> {
+    req.headers()
+        .get(name)
+        .and_then(|item| item.to_str().ok())
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .ok_or(Errors::new(format!("Missing {} header", name)))
+}
+
+/// Decode hex encoded signature
+pub fn decode_hex(value: &str) -> Result<Vec<u8>, Errors> {
+    hex::decode(value.trim()).map_err(|_| Errors::new("Invalid webhook signature encoding"))
+}
+
+/// Parse unix timestamp in seconds
+fn parse_timestamp(value: &str) -> Result<i64, Errors> {
+    value.trim().parse::<i64>().map_err(|_| Errors::new("Invalid webhook timestamp"))
+}
@@ -0,0 +1,9 @@
+pub mod formats;
+pub mod signatures;
+pub mod verified;
+pub mod webhooks;
+
+pub use crate::webhooks::formats::Format;
+pub use crate::webhooks::signatures::Signature;
+pub use crate::webhooks::verified::Verified;
+pub use crate::webhooks::webhooks::Webhook;
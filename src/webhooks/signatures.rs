@@ -0,0 +1,24 @@
+/// Struct container for the signature parts extracted from a webhook request
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Signature {
+    pub signatures: Vec<Vec<u8>>,
+    pub message: Vec<u8>,
+    pub timestamp: Option<i64>,
+}
+
+/// Signature implementation
+impl Signature {
+    /// Create new Signature instance
+    ///
+    /// Example
+    /// ```
+    /// use library::webhooks::Signature;
+    ///
+    /// fn main() {
+    ///     let signature = Signature::new();
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
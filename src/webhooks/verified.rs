@@ -0,0 +1,93 @@
+use actix_web::{Error, FromRequest, HttpRequest, HttpResponse};
+use actix_web::dev::Payload as DevPayload;
+use actix_web::error::InternalError;
+use actix_web::web::{Bytes, Data};
+use futures::future::LocalBoxFuture;
+use std::ops::Deref;
+
+use crate::Errors;
+use crate::Payload;
+use crate::webhooks::Webhook;
+
+/// Extractor for a webhook body whose signature has been verified against the `Webhook` app data
+///
+/// Example
+/// ```
+/// use actix_web::{App, web};
+/// use library::webhooks::{Verified, Webhook};
+///
+/// async fn stripe(body: Verified) -> String {
+///     format!("Received {} bytes", body.len())
+/// }
+///
+/// fn main() {
+///     App::new().service(
+///         web::resource("/webhooks/stripe")
+///             .app_data(web::Data::new(Webhook::stripe("whsec_secret")))
+///             .route(web::post().to(stripe))
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Verified(pub Bytes);
+
+/// Verified implementation
+impl Verified {
+    /// Unwrap verified bytes
+    pub fn into_inner(self) -> Bytes {
+        self.0
+    }
+
+    /// Deserialize verified bytes as json
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, Errors> {
+        serde_json::from_slice(&self.0).map_err(|error| Errors::new(format!("Json deserialize error: {}", error)))
+    }
+}
+
+/// Deref implementation for Verified
+impl Deref for Verified {
+    type Target = Bytes;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Implement from request
+impl FromRequest for Verified {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut DevPayload) -> Self::Future {
+        let req = req.clone();
+        let webhook = req.app_data::<Data<Webhook>>().cloned();
+        let bytes = Bytes::from_request(&req, payload);
+
+        Box::pin(async move {
+            // Check webhook configuration
+            let webhook = match webhook {
+                Some(webhook) => webhook,
+                None => {
+                    return Err(InternalError::from_response(
+                        Errors::new("Missing webhook configuration"),
+                        Payload::invalid_server_config()
+                    ).into());
+                }
+            };
+
+            // Buffer raw body and verify it
+            let body = bytes.await?;
+            if let Err(error) = webhook.verify(&req, &body) {
+                let mut payload = Payload::new(401);
+                payload.error = error.to_string();
+
+                return Err(InternalError::from_response(
+                    error,
+                    HttpResponse::Unauthorized().json(payload)
+                ).into());
+            }
+
+            Ok(Verified(body))
+        })
+    }
+}
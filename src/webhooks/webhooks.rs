@@ -0,0 +1,160 @@
+use actix_web::HttpRequest;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::Errors;
+use crate::webhooks::{Format, Signature};
+
+/// Struct container for webhook verification options. Register it as `app_data` on the scope that receives the webhook
+#[derive(Clone)]
+pub struct Webhook {
+    pub secret: Vec<u8>,
+    pub format: Format,
+    pub tolerance: i64,
+}
+
+/// Webhook implementation
+impl Webhook {
+    /// Creates Webhook instance with a 5 minute timestamp tolerance
+    ///
+    /// Example
+    /// ```
+    /// use library::webhooks::{Format, Webhook};
+    ///
+    /// fn main() {
+    ///     let webhook = Webhook::new("whsec_secret", Format::Stripe);
+    /// }
+    /// ```
+    pub fn new<T: Into<String>>(secret: T, format: Format) -> Self {
+        Self {
+            secret: secret.into().into_bytes(),
+            format,
+            tolerance: 300,
+        }
+    }
+
+    /// Creates Webhook instance for github signatures
+    pub fn github<T: Into<String>>(secret: T) -> Self {
+        Self::new(secret, Format::Github)
+    }
+
+    /// Creates Webhook instance for slack signatures
+    pub fn slack<T: Into<String>>(secret: T) -> Self {
+        Self::new(secret, Format::Slack)
+    }
+
+    /// Creates Webhook instance for stripe signatures
+    pub fn stripe<T: Into<String>>(secret: T) -> Self {
+        Self::new(secret, Format::Stripe)
+    }
+
+    /// Creates Webhook instance with a custom signature parser
+    ///
+    /// Example
+    /// ```
+    /// use library::webhooks::{formats, Signature, Webhook};
+    ///
+    /// fn main() {
+    ///     // Shopify style header where the signature covers the raw body
+    ///     let webhook = Webhook::custom("secret", |req, body| {
+    ///         let mut signature = Signature::new();
+    ///         signature.signatures.push(formats::decode_hex(&formats::get_header(req, "X-Signature")?)?);
+    ///         signature.message = body.to_vec();
+    ///
+    ///         Ok(signature)
+    ///     });
+    /// }
+    /// ```
+    pub fn custom<T: Into<String>>(secret: T, callback: fn(&HttpRequest, &[u8]) -> Result<Signature, Errors>) -> Self {
+        Self::new(secret, Format::Custom(callback))
+    }
+
+    /// Set timestamp tolerance in seconds for replay protection
+    ///
+    /// Example
+    /// ```
+    /// use library::webhooks::Webhook;
+    ///
+    /// fn main() {
+    ///     let mut webhook = Webhook::slack("secret");
+    ///     webhook.set_tolerance(60);
+    /// }
+    /// ```
+    pub fn set_tolerance(&mut self, seconds: i64) -> &mut Self {
+        self.tolerance = seconds;
+        self
+    }
+
+    /// Sign message with HMAC-SHA256 using the configured secret
+    ///
+    /// Example
+    /// ```
+    /// use library::webhooks::Webhook;
+    ///
+    /// fn main() {
+    ///     let webhook = Webhook::github("secret");
+    ///     let signature = hex::encode(webhook.sign(b"{}"));
+    /// }
+    /// ```
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+            .expect("HMAC can take key of any size");
+        mac.update(message);
+
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    /// Verify request signature and timestamp against the raw body
+    ///
+    /// Example
+    /// ```
+    /// use actix_web::test::TestRequest;
+    /// use library::webhooks::Webhook;
+    ///
+    /// fn main() {
+    ///     let webhook = Webhook::github("secret");
+    ///     let body = br#"{"action":"opened"}"#;
+    ///
+    ///     let req = TestRequest::post()
+    ///         .insert_header(("X-Hub-Signature-256", format!("sha256={}", hex::encode(webhook.sign(body)))))
+    ///         .to_http_request();
+    ///
+    ///     assert!(webhook.verify(&req, body).is_ok());
+    ///     assert!(webhook.verify(&req, b"tampered").is_err());
+    ///
+    ///     // Out of range timestamps are rejected instead of overflowing
+    ///     let webhook = Webhook::stripe("secret");
+    ///     let req = TestRequest::post()
+    ///         .insert_header(("Stripe-Signature", "t=-9223372036854775808,v1=00"))
+    ///         .to_http_request();
+    ///
+    ///     assert!(webhook.verify(&req, body).is_err());
+    /// }
+    /// ```
+    pub fn verify(&self, req: &HttpRequest, body: &[u8]) -> Result<(), Errors> {
+        // Retrieve signature parts
+        let signature = self.format.extract(req, body)?;
+
+        // Check timestamp tolerance
+        if let Some(timestamp) = signature.timestamp {
+            if Utc::now().timestamp().abs_diff(timestamp) > self.tolerance.max(0) as u64 {
+                return Err(Errors::unauthorized("Webhook timestamp is outside the tolerance window"));
+            }
+        }
+
+        // Compare signatures in constant time
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+            .expect("HMAC can take key of any size");
+        mac.update(&signature.message);
+
+        let is_valid = signature.signatures
+            .iter()
+            .any(|item| mac.clone().verify_slice(item).is_ok());
+
+        match is_valid {
+            true => Ok(()),
+            false => Err(Errors::unauthorized("Invalid webhook signature"))
+        }
+    }
+}